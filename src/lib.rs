@@ -0,0 +1,5 @@
+//! A native Rust implementation of the [Apache Iceberg](https://iceberg.apache.org/) table format.
+
+pub mod model;
+pub mod scan;
+pub mod writer;
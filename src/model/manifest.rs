@@ -1,5 +1,8 @@
 //! [manifest](https://iceberg.apache.org/spec/#manifests) and [partition](https://iceberg.apache.org/spec/#partition-specs) related structs
 
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 use serde_with::serde_as;
@@ -7,6 +10,10 @@ use serde_with::Bytes;
 use serde_with::DefaultOnNull;
 use serde_with::{DeserializeAs, SerializeAs};
 
+use super::schema::Schema;
+use super::types::Type;
+use super::values::Literal;
+
 #[serde_as]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 /// helper struct to deserialize a map of i32 to bytes
@@ -69,6 +76,135 @@ impl<'de> DeserializeAs<'de, (i32, i64)> for NumPair {
     }
 }
 
+/// The [format version](https://iceberg.apache.org/spec/#format-versioning) a manifest or
+/// manifest list was written with. Controls the sequence-number defaulting applied when
+/// parsing a manifest, and the on-disk field names used when writing a manifest list.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[repr(u8)]
+pub enum FormatVersion {
+    /// format version 1: no sequence numbers, no row-level deletes
+    V1 = 1,
+    /// format version 2: adds sequence numbers and row-level deletes
+    V2 = 2,
+}
+
+/// What a [manifest](https://iceberg.apache.org/spec/#manifests) references: either only data
+/// files, or only delete files (a manifest never mixes the two).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ManifestContent {
+    /// the manifest lists only data files
+    Data = 0,
+    /// the manifest lists only delete files (position or equality)
+    Deletes = 1,
+}
+
+impl TryFrom<i32> for ManifestContent {
+    type Error = anyhow::Error;
+
+    fn try_from(value: i32) -> Result<Self> {
+        Ok(match value {
+            0 => ManifestContent::Data,
+            1 => ManifestContent::Deletes,
+            other => anyhow::bail!("unknown manifest content: {other}"),
+        })
+    }
+}
+
+impl From<ManifestContent> for i32 {
+    fn from(value: ManifestContent) -> Self {
+        value as i32
+    }
+}
+
+impl ManifestContent {
+    /// The lowercase string a manifest's own `content` field (and metadata key) stores this as.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ManifestContent::Data => "data",
+            ManifestContent::Deletes => "deletes",
+        }
+    }
+}
+
+impl Serialize for ManifestContent {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(*self as i32)
+    }
+}
+
+impl<'de> Deserialize<'de> for ManifestContent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = i32::deserialize(deserializer)?;
+        ManifestContent::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// (De)serializes a [`ManifestContent`] as the lowercase string ("data"/"deletes") a manifest's
+/// own `content` metadata key is stored as, rather than the integer used by manifest list
+/// entries.
+mod manifest_content_as_str {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::ManifestContent;
+
+    pub fn serialize<S: Serializer>(value: &ManifestContent, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(value.as_str())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ManifestContent, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        match s.as_str() {
+            "data" => Ok(ManifestContent::Data),
+            "deletes" => Ok(ManifestContent::Deletes),
+            other => Err(serde::de::Error::custom(format!("unknown manifest content: '{other}'"))),
+        }
+    }
+}
+
+/// What a [`DataFile`] contains: actual table data, or the position/equality deletes that apply
+/// to other data files (merge-on-read).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DataFileContent {
+    /// the file stores table data
+    Data = 0,
+    /// the file stores position deletes
+    PositionDeletes = 1,
+    /// the file stores equality deletes
+    EqualityDeletes = 2,
+}
+
+impl TryFrom<i32> for DataFileContent {
+    type Error = anyhow::Error;
+
+    fn try_from(value: i32) -> Result<Self> {
+        Ok(match value {
+            0 => DataFileContent::Data,
+            1 => DataFileContent::PositionDeletes,
+            2 => DataFileContent::EqualityDeletes,
+            other => anyhow::bail!("unknown data file content: {other}"),
+        })
+    }
+}
+
+impl From<DataFileContent> for i32 {
+    fn from(value: DataFileContent) -> Self {
+        value as i32
+    }
+}
+
+impl Serialize for DataFileContent {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(*self as i32)
+    }
+}
+
+impl<'de> Deserialize<'de> for DataFileContent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = i32::deserialize(deserializer)?;
+        DataFileContent::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
 /// A manifest list file storing ptrs to manifest avro files
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ManifestList {
@@ -82,10 +218,8 @@ pub struct ManifestList {
     /// sequence number when the manifest was add to the table
     /// use 0 when reading manifest-format v1
     pub sequence_number: i32,
-    /// int with meaning:
-    /// - 0: data
-    /// - 1: deletes
-    pub content: i32,
+    /// whether the manifest lists data files or delete files
+    pub content: ManifestContent,
 }
 
 /// Manifest Lists files store manifest_file
@@ -104,30 +238,51 @@ pub struct ManifestFile {
     pub manifest_length: i64,
     /// ID of the snapshot where the manifest file was added
     pub added_snapshot_id: i64,
+    /// sequence number when the manifest was added to the table;
+    /// `null` when reading a v1 manifest list, in which case it must be treated as 0
+    #[serde(default)]
+    pub sequence_number: Option<i64>,
+    /// the minimum data sequence number of all live data or delete files in the manifest;
+    /// `null` when reading a v1 manifest list
+    #[serde(default)]
+    pub min_sequence_number: Option<i64>,
     /// Number of entries in the manifest that have status `ADDED`;
     /// when `null` is assumed to be none zero
+    #[serde(alias = "added_data_files_count")]
     pub added_files_count: Option<i32>,
     /// Number of entries in the manifest that have status `EXISTING`;
     /// when `null` this is assumed to be none zero
+    #[serde(alias = "existing_data_files_count")]
     pub existing_files_count: Option<i32>,
     /// Number of entries in the manifest that have status `DELETED`;
     /// when `null` this is assumed to be none zero
+    ///
+    /// Misnamed relative to the spec's `deleted_files_count`; kept for backwards
+    /// compatibility and aliased so both names deserialize.
+    #[serde(alias = "deleted_data_files_count", alias = "deleted_files_count")]
     pub deleted_fields_count: Option<i32>,
     /// A list of field summaries for each partition field in the spec.
     /// Each field in the list corresponds to a field in the manifest file's partition spec
     pub partitions: Vec<FieldSummary>,
     /// Number of rows in all the files in the manifest that have status `ADDED`;
     /// when `null` is assumed to be none zero
+    #[serde(alias = "added_data_rows_count")]
     pub added_rows_count: Option<i64>,
     /// Number of rows in all the files in the manifest that have status `EXISTING`;
     /// when `null` this is assumed to be none zero
+    #[serde(alias = "existing_data_rows_count")]
     pub existing_rows_count: Option<i64>,
     /// Number of rows in all the files in the manifest that have status `DELETED`;
     /// when `null` this is assumed to be none zero
+    #[serde(alias = "deleted_data_rows_count")]
     pub deleted_rows_count: Option<i64>,
     /// ID of a partition spec used to write the manifest;
     /// must be listed in table metadata's `partition-specs`
     pub partition_spec_id: i32,
+    /// whether the manifest lists data files or delete files; `null` when reading a v1
+    /// manifest list, where every file is a data file
+    #[serde(default)]
+    pub content: Option<ManifestContent>,
 }
 
 /// field summary of manifest list
@@ -151,6 +306,21 @@ pub struct FieldSummary {
     pub upper_bound: Vec<u8>,
 }
 
+impl FieldSummary {
+    /// Decodes [`Self::lower_bound`] and [`Self::upper_bound`] into typed values, given the
+    /// type of the partition field they summarize. Returns `None` for a bound that is empty,
+    /// meaning every value of the field is null or NaN.
+    pub fn typed_bounds(&self, r#type: &Type) -> Result<(Option<Literal>, Option<Literal>)> {
+        let lower = (!self.lower_bound.is_empty())
+            .then(|| Literal::try_from_bytes(r#type, &self.lower_bound))
+            .transpose()?;
+        let upper = (!self.upper_bound.is_empty())
+            .then(|| Literal::try_from_bytes(r#type, &self.upper_bound))
+            .transpose()?;
+        Ok((lower, upper))
+    }
+}
+
 /// A manifest is an immutable Avro file that
 /// lists data files or delete files,
 /// along with each file’s partition data tuple, metrics, and tracking information.
@@ -168,8 +338,9 @@ pub struct Manifest {
     pub partition_spec_id: String,
     /// the version of format
     pub format_version: i64,
-    /// a enum indicating diff type, add or sub
-    pub content: String,
+    /// whether the manifest lists data files or delete files
+    #[serde(with = "manifest_content_as_str")]
+    pub content: ManifestContent,
 }
 
 /// The manifest entry is a struct that contains the metadata of the file
@@ -193,6 +364,59 @@ pub struct ManifestEntry {
     pub file_sequence_number: Option<i64>,
 }
 
+/// status of a [`ManifestEntry`] indicating the entry was newly added to the table
+const MANIFEST_ENTRY_STATUS_ADDED: i32 = 1;
+
+impl ManifestEntry {
+    /// Applies [sequence-number inheritance](https://iceberg.apache.org/spec/#sequence-numbers):
+    /// a null `snapshot_id` is filled from the owning manifest's `added_snapshot_id`, and for
+    /// entries with status `ADDED`, null `sequence_number`/`file_sequence_number` are filled
+    /// from the owning manifest's `sequence_number`.
+    pub fn inherit_sequence_numbers(&mut self, manifest_sequence_number: i64, manifest_snapshot_id: i64) {
+        if self.snapshot_id.is_none() {
+            self.snapshot_id = Some(manifest_snapshot_id);
+        }
+        if self.status == MANIFEST_ENTRY_STATUS_ADDED {
+            if self.sequence_number.is_none() {
+                self.sequence_number = Some(manifest_sequence_number);
+            }
+            if self.file_sequence_number.is_none() {
+                self.file_sequence_number = Some(manifest_sequence_number);
+            }
+        }
+    }
+}
+
+/// Parses the manifest entries encoded in an Avro manifest file's `bytes`, applying
+/// [`FormatVersion`]-specific defaults and [sequence-number inheritance](https://iceberg.apache.org/spec/#sequence-numbers)
+/// from the owning manifest's `sequence_number` and `added_snapshot_id` (as found in its
+/// [`ManifestFile`] entry in the manifest list).
+///
+/// In a v1 manifest, entries carry no sequence number at all, so `manifest_sequence_number`
+/// is ignored and entries with status `ADDED` inherit sequence number 0; other entries keep
+/// a null sequence number.
+pub fn parse_with_version(
+    bytes: &[u8],
+    version: FormatVersion,
+    manifest_sequence_number: i64,
+    manifest_snapshot_id: i64,
+) -> Result<Vec<ManifestEntry>> {
+    let reader = apache_avro::Reader::new(bytes)
+        .map_err(|e| anyhow!("failed to open manifest file: {e:?}"))?;
+    let inherited_sequence_number = match version {
+        FormatVersion::V1 => 0,
+        FormatVersion::V2 => manifest_sequence_number,
+    };
+    reader
+        .map(|value| {
+            let mut entry: ManifestEntry = apache_avro::from_value(&value?)
+                .map_err(|e| anyhow!("failed to parse manifest entry: {e:?}"))?;
+            entry.inherit_sequence_numbers(inherited_sequence_number, manifest_snapshot_id);
+            Ok(entry)
+        })
+        .collect()
+}
+
 /// the data file is a struct that contains the metadata of the file
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -250,7 +474,7 @@ pub struct DataFile {
     #[serde(default)]
     equality_ids: Vec<i32>,
     /// Type of content stored by the data file: data, equality deletes, or position deletes (all v1 files are data files)
-    content: i32,
+    content: DataFileContent,
     /// Map from column id to number of nan values in the column
     #[serde(default)]
     #[serde_as(as = "DefaultOnNull<Vec<NumPair>>")]
@@ -259,15 +483,365 @@ pub struct DataFile {
     sort_order_id: i32,
 }
 
+impl DataFile {
+    /// Decodes [`Self::lower_bounds`] into typed values, keyed by column id, looking up each
+    /// column's type in `schema`.
+    pub fn typed_lower_bounds(&self, schema: &Schema) -> Result<HashMap<i32, Literal>> {
+        decode_typed_bounds(&self.lower_bounds, schema)
+    }
+
+    /// Decodes [`Self::upper_bounds`] into typed values, keyed by column id, looking up each
+    /// column's type in `schema`.
+    pub fn typed_upper_bounds(&self, schema: &Schema) -> Result<HashMap<i32, Literal>> {
+        decode_typed_bounds(&self.upper_bounds, schema)
+    }
+
+    /// Joins every per-column statistic this file carries for `column_id` -- value, null and
+    /// NaN counts, distinct count, on-disk size, and typed lower/upper bounds -- into a single
+    /// [`ColumnStats`]. `column_id`'s type is looked up in `schema` to decode the bounds.
+    pub fn column_stats(&self, column_id: i32, schema: &Schema) -> Result<ColumnStats> {
+        let field_type = schema
+            .field_type(column_id)
+            .ok_or_else(|| anyhow!("schema has no field with id {column_id}"))?;
+        let bound = |bounds: &[(i32, Vec<u8>)]| -> Result<Option<Literal>> {
+            bounds
+                .iter()
+                .find(|(id, _)| *id == column_id)
+                .map(|(_, bytes)| Literal::try_from_bytes(field_type, bytes))
+                .transpose()
+        };
+        Ok(ColumnStats {
+            value_count: lookup_count(&self.value_counts, column_id),
+            null_count: lookup_count(&self.null_value_counts, column_id),
+            nan_count: lookup_count(&self.nan_value_counts, column_id),
+            distinct_count: lookup_count(&self.distinct_counts, column_id),
+            size_bytes: lookup_count(&self.column_sizes, column_id),
+            min: bound(&self.lower_bounds)?,
+            max: bound(&self.upper_bounds)?,
+        })
+    }
+
+    /// path to the data file
+    pub fn file_path(&self) -> &str {
+        &self.file_path
+    }
+
+    /// format of the data file: Avro, Orc or Parquet
+    pub fn file_format(&self) -> &str {
+        &self.file_format
+    }
+
+    /// partition data tuple, schema based on the partition spec output using partition field
+    /// ids for the struct field ids
+    pub fn partition(&self) -> &Value {
+        &self.partition
+    }
+
+    /// number of records in the file
+    pub fn record_count(&self) -> i64 {
+        self.record_count
+    }
+
+    /// total file size in bytes
+    pub fn file_size_in_bytes(&self) -> i64 {
+        self.file_size_in_bytes
+    }
+
+    /// Map from column id to the total size on disk of all regions that store the column
+    pub fn column_sizes(&self) -> &[(i32, i64)] {
+        &self.column_sizes
+    }
+
+    /// Map from column id to number of values in the column (NULL and NaN included)
+    pub fn value_counts(&self) -> &[(i32, i64)] {
+        &self.value_counts
+    }
+
+    /// Map from column id to number of null values in the column
+    pub fn null_value_counts(&self) -> &[(i32, i64)] {
+        &self.null_value_counts
+    }
+
+    /// Map from column id to number of nan values in the column
+    pub fn nan_value_counts(&self) -> &[(i32, i64)] {
+        &self.nan_value_counts
+    }
+
+    /// Map from column id to number of distinct values in the column
+    pub fn distinct_counts(&self) -> &[(i32, i64)] {
+        &self.distinct_counts
+    }
+
+    /// Map from column id to the encoded minimum value in the column; decode with
+    /// [`Self::typed_lower_bounds`] or [`Self::column_stats`]
+    pub fn lower_bounds(&self) -> &[(i32, Vec<u8>)] {
+        &self.lower_bounds
+    }
+
+    /// Map from column id to the encoded maximum value in the column; decode with
+    /// [`Self::typed_upper_bounds`] or [`Self::column_stats`]
+    pub fn upper_bounds(&self) -> &[(i32, Vec<u8>)] {
+        &self.upper_bounds
+    }
+
+    /// Implementation-specific key metadata for encryption
+    pub fn key_metadata(&self) -> &[u8] {
+        &self.key_metadata
+    }
+
+    /// Split offsets for the data file, e.g. row group offsets in a Parquet file
+    pub fn split_offsets(&self) -> &[i64] {
+        &self.split_offsets
+    }
+
+    /// Field ids used to determine row equality in equality delete files; empty unless
+    /// [`Self::content`] is `EqualityDeletes`
+    pub fn equality_ids(&self) -> &[i32] {
+        &self.equality_ids
+    }
+
+    /// Type of content stored by the data file: data, position deletes, or equality deletes.
+    pub fn content(&self) -> DataFileContent {
+        self.content
+    }
+
+    /// ID representing the sort order of this file
+    pub fn sort_order_id(&self) -> i32 {
+        self.sort_order_id
+    }
+
+    /// Checks the [spec invariant](https://iceberg.apache.org/spec/#manifests) that
+    /// [`Self::equality_ids`] is set if and only if [`Self::content`] is `EqualityDeletes`.
+    pub fn validate(&self) -> Result<()> {
+        match self.content {
+            DataFileContent::EqualityDeletes => {
+                if self.equality_ids.is_empty() {
+                    anyhow::bail!("equality_ids must be set when content is EqualityDeletes");
+                }
+            }
+            DataFileContent::Data | DataFileContent::PositionDeletes => {
+                if !self.equality_ids.is_empty() {
+                    anyhow::bail!("equality_ids must be empty unless content is EqualityDeletes");
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Checks the [spec invariant](https://iceberg.apache.org/spec/#manifests) that a manifest
+/// references only data files or only delete files, never both, and returns which one
+/// `entries` reference. An empty `entries` carries no information either way, so it is
+/// reported as `Data`.
+pub fn validate_manifest_content(entries: &[ManifestEntry]) -> Result<ManifestContent> {
+    let mut content = None;
+    for entry in entries {
+        let entry_content = match entry.data_file.content() {
+            DataFileContent::Data => ManifestContent::Data,
+            DataFileContent::PositionDeletes | DataFileContent::EqualityDeletes => ManifestContent::Deletes,
+        };
+        match content {
+            None => content = Some(entry_content),
+            Some(existing) if existing == entry_content => {}
+            Some(_) => anyhow::bail!("manifest references both data files and delete files"),
+        }
+    }
+    Ok(content.unwrap_or(ManifestContent::Data))
+}
+
+fn decode_typed_bounds(bounds: &[(i32, Vec<u8>)], schema: &Schema) -> Result<HashMap<i32, Literal>> {
+    bounds
+        .iter()
+        .map(|(id, bytes)| {
+            let field_type = schema
+                .field_type(*id)
+                .ok_or_else(|| anyhow!("schema has no field with id {id}"))?;
+            Ok((*id, Literal::try_from_bytes(field_type, bytes)?))
+        })
+        .collect()
+}
+
+/// A single column's statistics, joined from a [`DataFile`]'s parallel per-column maps and
+/// typed bounds by [`DataFile::column_stats`]. Any field is `None` if the file carries no
+/// statistic for that column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnStats {
+    /// number of values in the column, NULL and NaN included
+    pub value_count: Option<i64>,
+    /// number of null values in the column
+    pub null_count: Option<i64>,
+    /// number of NaN values in the column
+    pub nan_count: Option<i64>,
+    /// number of distinct values in the column
+    pub distinct_count: Option<i64>,
+    /// total size on disk of all regions that store the column
+    pub size_bytes: Option<i64>,
+    /// minimum non-null, non-NaN value in the column
+    pub min: Option<Literal>,
+    /// maximum non-null, non-NaN value in the column
+    pub max: Option<Literal>,
+}
+
+fn lookup_count(counts: &[(i32, i64)], column_id: i32) -> Option<i64> {
+    counts
+        .iter()
+        .find(|(id, _)| *id == column_id)
+        .map(|(_, count)| *count)
+}
+
 #[cfg(test)]
 mod test {
     use anyhow::anyhow;
     use anyhow::Result;
     use apache_avro::from_value;
 
+    use crate::model::manifest::FormatVersion;
     use crate::model::manifest::Manifest;
     use crate::model::manifest::ManifestEntry;
 
+    #[test]
+    pub fn test_parse_manifest_entries_with_version() -> Result<()> {
+        let manifest_path = "test-data/metadata/9624c71f-198f-47fe-824b-0291f8998018-m1.avro";
+        let bytes = std::fs::read(manifest_path)?;
+        let entries = super::parse_with_version(&bytes, FormatVersion::V2, 1, 6560075252320843098)?;
+        for entry in entries {
+            assert!(entry.snapshot_id.is_some());
+        }
+        Ok(())
+    }
+
+    fn test_data_file() -> super::DataFile {
+        super::DataFile {
+            file_path: "s3://bucket/data/file.parquet".to_string(),
+            file_format: "PARQUET".to_string(),
+            partition: serde_json::Value::Null,
+            record_count: 1,
+            file_size_in_bytes: 1,
+            column_sizes: Vec::new(),
+            value_counts: Vec::new(),
+            null_value_counts: Vec::new(),
+            distinct_counts: Vec::new(),
+            lower_bounds: Vec::new(),
+            upper_bounds: Vec::new(),
+            key_metadata: Vec::new(),
+            split_offsets: Vec::new(),
+            equality_ids: Vec::new(),
+            content: super::DataFileContent::Data,
+            nan_value_counts: Vec::new(),
+            sort_order_id: 0,
+        }
+    }
+
+    #[test]
+    pub fn test_column_stats_joins_per_column_maps_and_typed_bounds() {
+        use crate::model::schema::Schema;
+        use crate::model::types::{PrimitiveType, StructField, Type};
+
+        let schema = Schema {
+            type_tag: "struct".to_string(),
+            schema_id: 0,
+            identifier_field_ids: vec![],
+            fields: vec![StructField {
+                id: 1,
+                name: "amount".to_string(),
+                required: true,
+                field_type: Type::Primitive(PrimitiveType::Int),
+                doc: None,
+            }],
+        };
+        let mut data_file = test_data_file();
+        data_file.value_counts = vec![(1, 100)];
+        data_file.null_value_counts = vec![(1, 5)];
+        data_file.nan_value_counts = vec![(1, 0)];
+        data_file.distinct_counts = vec![(1, 42)];
+        data_file.column_sizes = vec![(1, 1024)];
+        data_file.lower_bounds = vec![(1, 1i32.to_le_bytes().to_vec())];
+        data_file.upper_bounds = vec![(1, 99i32.to_le_bytes().to_vec())];
+
+        let stats = data_file.column_stats(1, &schema).unwrap();
+        assert_eq!(stats.value_count, Some(100));
+        assert_eq!(stats.null_count, Some(5));
+        assert_eq!(stats.nan_count, Some(0));
+        assert_eq!(stats.distinct_count, Some(42));
+        assert_eq!(stats.size_bytes, Some(1024));
+        assert_eq!(stats.min, Some(super::Literal::Int(1)));
+        assert_eq!(stats.max, Some(super::Literal::Int(99)));
+
+        let missing_column = data_file.column_stats(2, &schema);
+        assert!(missing_column.is_err());
+    }
+
+    #[test]
+    pub fn test_inherit_sequence_numbers_v1_defaults_to_zero() {
+        let mut entry = ManifestEntry {
+            status: 1,
+            snapshot_id: None,
+            data_file: test_data_file(),
+            sequence_number: None,
+            file_sequence_number: None,
+        };
+        // V1 manifests never carry a sequence number, so `inherit_sequence_numbers` is always
+        // called with 0 for them; only added entries without one already should pick it up.
+        entry.inherit_sequence_numbers(0, 42);
+        assert_eq!(entry.snapshot_id, Some(42));
+        assert_eq!(entry.sequence_number, Some(0));
+        assert_eq!(entry.file_sequence_number, Some(0));
+    }
+
+    #[test]
+    pub fn test_inherit_sequence_numbers_existing_entry_keeps_its_own() {
+        let mut entry = ManifestEntry {
+            status: 0, // EXISTING
+            snapshot_id: Some(7),
+            data_file: test_data_file(),
+            sequence_number: Some(3),
+            file_sequence_number: Some(3),
+        };
+        entry.inherit_sequence_numbers(9, 42);
+        assert_eq!(entry.snapshot_id, Some(7));
+        assert_eq!(entry.sequence_number, Some(3));
+        assert_eq!(entry.file_sequence_number, Some(3));
+    }
+
+    #[test]
+    pub fn test_data_file_validate_requires_equality_ids_for_equality_deletes() {
+        let mut data_file = test_data_file();
+        data_file.content = super::DataFileContent::EqualityDeletes;
+        assert!(data_file.validate().is_err());
+        data_file.equality_ids = vec![1];
+        assert!(data_file.validate().is_ok());
+    }
+
+    #[test]
+    pub fn test_data_file_validate_rejects_equality_ids_on_data_file() {
+        let mut data_file = test_data_file();
+        data_file.equality_ids = vec![1];
+        assert!(data_file.validate().is_err());
+    }
+
+    #[test]
+    pub fn test_validate_manifest_content_rejects_mixed_files() {
+        let mut deletes = test_data_file();
+        deletes.content = super::DataFileContent::PositionDeletes;
+        let entries = vec![
+            ManifestEntry {
+                status: 1,
+                snapshot_id: Some(1),
+                data_file: test_data_file(),
+                sequence_number: Some(1),
+                file_sequence_number: Some(1),
+            },
+            ManifestEntry {
+                status: 1,
+                snapshot_id: Some(1),
+                data_file: deletes,
+                sequence_number: Some(1),
+                file_sequence_number: Some(1),
+            },
+        ];
+        assert!(super::validate_manifest_content(&entries).is_err());
+    }
+
     #[test]
     pub fn test_parse_manifest_lists() -> Result<()> {
         let manifest_list_path = "test-data/metadata/snap-6560075252320843098-1-9624c71f-198f-47fe-824b-0291f8998018.avro";
@@ -292,4 +866,47 @@ mod test {
         }
         Ok(())
     }
+
+    #[test]
+    pub fn test_manifest_file_deserializes_v1_5_0_field_aliases() -> Result<()> {
+        use crate::model::manifest::ManifestFile;
+
+        let manifest_file: ManifestFile = serde_json::from_value(serde_json::json!({
+            "manifest_path": "s3://bucket/metadata/m1.avro",
+            "manifest_length": 1234,
+            "partition_spec_id": 0,
+            "added_snapshot_id": 1,
+            "added_data_files_count": 1,
+            "existing_data_files_count": 2,
+            "deleted_data_files_count": 3,
+            "partitions": [],
+            "added_data_rows_count": 10,
+            "existing_data_rows_count": 20,
+            "deleted_data_rows_count": 30,
+        }))?;
+
+        assert_eq!(manifest_file.added_files_count, Some(1));
+        assert_eq!(manifest_file.existing_files_count, Some(2));
+        assert_eq!(manifest_file.deleted_fields_count, Some(3));
+        assert_eq!(manifest_file.added_rows_count, Some(10));
+        assert_eq!(manifest_file.existing_rows_count, Some(20));
+        assert_eq!(manifest_file.deleted_rows_count, Some(30));
+
+        let manifest_file: ManifestFile = serde_json::from_value(serde_json::json!({
+            "manifest_path": "s3://bucket/metadata/m1.avro",
+            "manifest_length": 1234,
+            "partition_spec_id": 0,
+            "added_snapshot_id": 1,
+            "added_files_count": null,
+            "existing_files_count": null,
+            "deleted_files_count": 3,
+            "partitions": [],
+            "added_rows_count": null,
+            "existing_rows_count": null,
+            "deleted_rows_count": null,
+        }))?;
+        assert_eq!(manifest_file.deleted_fields_count, Some(3));
+
+        Ok(())
+    }
 }
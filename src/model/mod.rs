@@ -0,0 +1,8 @@
+//! Typed in-memory representation of an Iceberg table's metadata: its
+//! [schema](https://iceberg.apache.org/spec/#schemas), [manifests](https://iceberg.apache.org/spec/#manifests)
+//! and the values stored inside them.
+
+pub mod manifest;
+pub mod schema;
+pub mod types;
+pub mod values;
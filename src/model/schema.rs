@@ -0,0 +1,68 @@
+//! The Iceberg table [schema](https://iceberg.apache.org/spec/#schemas): a named, versioned
+//! struct type whose fields are identified by a stable id rather than by position.
+
+use serde::{Deserialize, Serialize};
+
+use super::types::{StructField, Type};
+
+/// A table schema: a struct type plus the bookkeeping Iceberg attaches to it
+/// (its id and which fields form the identifier).
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Schema {
+    /// always the literal string `"struct"`, kept for round-tripping the on-disk representation
+    /// (mirroring [`StructType::type_tag`](super::types::StructType::type_tag)) — a table schema
+    /// is itself a struct type at the top level
+    #[serde(rename = "type")]
+    pub type_tag: String,
+    /// id of this schema version, unique within the table's metadata
+    pub schema_id: i32,
+    /// ids of the fields that uniquely identify a row, if any
+    #[serde(default)]
+    pub identifier_field_ids: Vec<i32>,
+    /// the fields of the schema
+    pub fields: Vec<StructField>,
+}
+
+impl Schema {
+    /// Looks up the type of the field with the given id, searching nested
+    /// struct, list and map fields as well as the top-level fields.
+    pub fn field_type(&self, id: i32) -> Option<&Type> {
+        fn search(fields: &[StructField], id: i32) -> Option<&Type> {
+            for field in fields {
+                if field.id == id {
+                    return Some(&field.field_type);
+                }
+                if let Some(found) = search_type(&field.field_type, id) {
+                    return Some(found);
+                }
+            }
+            None
+        }
+
+        fn search_type(field_type: &Type, id: i32) -> Option<&Type> {
+            match field_type {
+                Type::Struct(s) => search(&s.fields, id),
+                Type::List(l) => {
+                    if l.element_id == id {
+                        Some(l.element.as_ref())
+                    } else {
+                        search_type(l.element.as_ref(), id)
+                    }
+                }
+                Type::Map(m) => {
+                    if m.key_id == id {
+                        Some(m.key.as_ref())
+                    } else if m.value_id == id {
+                        Some(m.value.as_ref())
+                    } else {
+                        search_type(m.key.as_ref(), id).or_else(|| search_type(m.value.as_ref(), id))
+                    }
+                }
+                Type::Primitive(_) => None,
+            }
+        }
+
+        search(&self.fields, id)
+    }
+}
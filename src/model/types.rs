@@ -0,0 +1,196 @@
+//! Iceberg's [type system](https://iceberg.apache.org/spec/#schemas-and-data-types): primitive
+//! types plus the nested `struct`, `list` and `map` types.
+
+use serde::{Deserialize, Serialize};
+
+/// A field of a [`StructType`], uniquely identified within a [`Schema`](super::schema::Schema)
+/// by its `id`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct StructField {
+    /// unique id of the field inside the schema
+    pub id: i32,
+    /// name of the field
+    pub name: String,
+    /// whether the field may not be null
+    pub required: bool,
+    /// type of the field
+    #[serde(rename = "type")]
+    pub field_type: Type,
+    /// optional documentation for the field
+    #[serde(default)]
+    pub doc: Option<String>,
+}
+
+/// A struct type is a tuple of typed fields, each with a unique id
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct StructType {
+    /// always the literal string `"struct"`, kept for round-tripping the on-disk representation
+    #[serde(rename = "type")]
+    pub type_tag: String,
+    /// the fields of the struct
+    pub fields: Vec<StructField>,
+}
+
+/// A list type, with a single, typed element field
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct ListType {
+    /// always the literal string `"list"`, kept for round-tripping the on-disk representation
+    #[serde(rename = "type")]
+    pub type_tag: String,
+    /// unique id of the element field
+    pub element_id: i32,
+    /// whether the element may not be null
+    pub element_required: bool,
+    /// type of the element
+    pub element: Box<Type>,
+}
+
+/// A map type, with typed key and value fields
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct MapType {
+    /// always the literal string `"map"`, kept for round-tripping the on-disk representation
+    #[serde(rename = "type")]
+    pub type_tag: String,
+    /// unique id of the key field
+    pub key_id: i32,
+    /// type of the key
+    pub key: Box<Type>,
+    /// unique id of the value field
+    pub value_id: i32,
+    /// whether the value may not be null
+    pub value_required: bool,
+    /// type of the value
+    pub value: Box<Type>,
+}
+
+/// An Iceberg [primitive type](https://iceberg.apache.org/spec/#primitive-types).
+///
+/// Serialized as a bare string, e.g. `"long"`, `"decimal(9,2)"` or `"fixed[16]"`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PrimitiveType {
+    /// `boolean`
+    Boolean,
+    /// `int`, a 32-bit signed integer
+    Int,
+    /// `long`, a 64-bit signed integer
+    Long,
+    /// `float`, a 32-bit IEEE 754 floating point number
+    Float,
+    /// `double`, a 64-bit IEEE 754 floating point number
+    Double,
+    /// `decimal(P,S)`, a fixed-point decimal number with precision `P` and scale `S`
+    Decimal {
+        /// total number of digits
+        precision: u32,
+        /// number of digits to the right of the decimal point
+        scale: u32,
+    },
+    /// `date`, stored as days since the unix epoch
+    Date,
+    /// `time`, stored as microseconds since midnight
+    Time,
+    /// `timestamp` without a timezone, stored as microseconds since the unix epoch
+    Timestamp,
+    /// `timestamptz`, a timestamp with a timezone, stored as microseconds since the unix epoch
+    Timestamptz,
+    /// `string`, arbitrary-length UTF-8 text
+    String,
+    /// `uuid`
+    Uuid,
+    /// `fixed(L)`, a fixed-length byte array of `L` bytes
+    Fixed(u64),
+    /// `binary`, arbitrary-length byte array
+    Binary,
+}
+
+impl PrimitiveType {
+    fn parse(s: &str) -> Result<Self, String> {
+        Ok(match s {
+            "boolean" => PrimitiveType::Boolean,
+            "int" => PrimitiveType::Int,
+            "long" => PrimitiveType::Long,
+            "float" => PrimitiveType::Float,
+            "double" => PrimitiveType::Double,
+            "date" => PrimitiveType::Date,
+            "time" => PrimitiveType::Time,
+            "timestamp" => PrimitiveType::Timestamp,
+            "timestamptz" => PrimitiveType::Timestamptz,
+            "string" => PrimitiveType::String,
+            "uuid" => PrimitiveType::Uuid,
+            "binary" => PrimitiveType::Binary,
+            s if s.starts_with("fixed[") && s.ends_with(']') => {
+                let len = s[6..s.len() - 1]
+                    .parse::<u64>()
+                    .map_err(|_| format!("invalid fixed type: '{s}'"))?;
+                PrimitiveType::Fixed(len)
+            }
+            s if s.starts_with("decimal(") && s.ends_with(')') => {
+                let mut parts = s[8..s.len() - 1].splitn(2, ',');
+                let precision = parts
+                    .next()
+                    .and_then(|p| p.trim().parse::<u32>().ok())
+                    .ok_or_else(|| format!("invalid decimal type: '{s}'"))?;
+                let scale = parts
+                    .next()
+                    .and_then(|p| p.trim().parse::<u32>().ok())
+                    .ok_or_else(|| format!("invalid decimal type: '{s}'"))?;
+                PrimitiveType::Decimal { precision, scale }
+            }
+            s => return Err(format!("unknown primitive type: '{s}'")),
+        })
+    }
+
+    fn to_string(&self) -> String {
+        match self {
+            PrimitiveType::Boolean => "boolean".to_string(),
+            PrimitiveType::Int => "int".to_string(),
+            PrimitiveType::Long => "long".to_string(),
+            PrimitiveType::Float => "float".to_string(),
+            PrimitiveType::Double => "double".to_string(),
+            PrimitiveType::Date => "date".to_string(),
+            PrimitiveType::Time => "time".to_string(),
+            PrimitiveType::Timestamp => "timestamp".to_string(),
+            PrimitiveType::Timestamptz => "timestamptz".to_string(),
+            PrimitiveType::String => "string".to_string(),
+            PrimitiveType::Uuid => "uuid".to_string(),
+            PrimitiveType::Binary => "binary".to_string(),
+            PrimitiveType::Fixed(len) => format!("fixed[{len}]"),
+            PrimitiveType::Decimal { precision, scale } => format!("decimal({precision},{scale})"),
+        }
+    }
+}
+
+impl Serialize for PrimitiveType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PrimitiveType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        PrimitiveType::parse(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// A field's type: either a [`PrimitiveType`] or a nested `struct`, `list` or `map`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum Type {
+    /// a primitive type
+    Primitive(PrimitiveType),
+    /// a nested struct type
+    Struct(StructType),
+    /// a nested list type
+    List(ListType),
+    /// a nested map type
+    Map(MapType),
+}
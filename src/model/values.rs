@@ -0,0 +1,317 @@
+//! Typed representation of the single Iceberg values found in partition and column bounds,
+//! and their [binary encoding](https://iceberg.apache.org/spec/#appendix-d-single-value-serialization).
+
+use anyhow::{anyhow, bail, Result};
+
+use super::types::{PrimitiveType, Type};
+
+/// A single decoded Iceberg value, as found in `FieldSummary` partition bounds
+/// and `DataFile` column bounds.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Literal {
+    /// `boolean`
+    Boolean(bool),
+    /// `int`
+    Int(i32),
+    /// `long`
+    Long(i64),
+    /// `float`
+    Float(f32),
+    /// `double`
+    Double(f64),
+    /// `decimal(P,S)`, as the unscaled value together with its scale
+    Decimal {
+        /// the unscaled value
+        unscaled: i128,
+        /// number of digits to the right of the decimal point
+        scale: u32,
+    },
+    /// `date`, days since the unix epoch
+    Date(i32),
+    /// `time`, microseconds since midnight
+    Time(i64),
+    /// `timestamp`, microseconds since the unix epoch
+    Timestamp(i64),
+    /// `timestamptz`, microseconds since the unix epoch
+    Timestamptz(i64),
+    /// `string`
+    String(String),
+    /// `uuid`
+    Uuid([u8; 16]),
+    /// `fixed(L)`
+    Fixed(Vec<u8>),
+    /// `binary`
+    Binary(Vec<u8>),
+}
+
+impl Literal {
+    /// Decodes the [single-value binary serialization](https://iceberg.apache.org/spec/#appendix-d-single-value-serialization)
+    /// of an Iceberg value into a typed [`Literal`].
+    pub fn try_from_bytes(r#type: &Type, bytes: &[u8]) -> Result<Self> {
+        let primitive = match r#type {
+            Type::Primitive(primitive) => primitive,
+            other => bail!("{other:?} has no single-value serialization"),
+        };
+        Ok(match primitive {
+            PrimitiveType::Boolean => {
+                if bytes.len() != 1 {
+                    bail!("boolean value must be 1 byte, got {}", bytes.len());
+                }
+                Literal::Boolean(bytes[0] != 0x00)
+            }
+            PrimitiveType::Int => Literal::Int(i32::from_le_bytes(bytes.try_into()?)),
+            PrimitiveType::Long => Literal::Long(i64::from_le_bytes(bytes.try_into()?)),
+            PrimitiveType::Float => Literal::Float(f32::from_le_bytes(bytes.try_into()?)),
+            PrimitiveType::Double => Literal::Double(f64::from_le_bytes(bytes.try_into()?)),
+            PrimitiveType::Date => Literal::Date(i32::from_le_bytes(bytes.try_into()?)),
+            PrimitiveType::Time => Literal::Time(i64::from_le_bytes(bytes.try_into()?)),
+            PrimitiveType::Timestamp => Literal::Timestamp(i64::from_le_bytes(bytes.try_into()?)),
+            PrimitiveType::Timestamptz => Literal::Timestamptz(i64::from_le_bytes(bytes.try_into()?)),
+            PrimitiveType::String => Literal::String(String::from_utf8(bytes.to_vec())?),
+            PrimitiveType::Uuid => {
+                let array: [u8; 16] = bytes
+                    .try_into()
+                    .map_err(|_| anyhow!("uuid value must be 16 bytes, got {}", bytes.len()))?;
+                Literal::Uuid(array)
+            }
+            PrimitiveType::Fixed(len) => {
+                if bytes.len() as u64 != *len {
+                    bail!("fixed({len}) value must be {len} bytes, got {}", bytes.len());
+                }
+                Literal::Fixed(bytes.to_vec())
+            }
+            PrimitiveType::Binary => Literal::Binary(bytes.to_vec()),
+            PrimitiveType::Decimal { scale, .. } => Literal::Decimal {
+                unscaled: decode_decimal(bytes)?,
+                scale: *scale,
+            },
+        })
+    }
+
+    /// Encodes this value back into the single-value binary serialization used by
+    /// `FieldSummary` and `DataFile` bounds. The inverse of [`Self::try_from_bytes`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Literal::Boolean(b) => vec![if *b { 0x01 } else { 0x00 }],
+            Literal::Int(v) => v.to_le_bytes().to_vec(),
+            Literal::Long(v) => v.to_le_bytes().to_vec(),
+            Literal::Float(v) => v.to_le_bytes().to_vec(),
+            Literal::Double(v) => v.to_le_bytes().to_vec(),
+            Literal::Date(v) => v.to_le_bytes().to_vec(),
+            Literal::Time(v) => v.to_le_bytes().to_vec(),
+            Literal::Timestamp(v) => v.to_le_bytes().to_vec(),
+            Literal::Timestamptz(v) => v.to_le_bytes().to_vec(),
+            Literal::String(s) => s.as_bytes().to_vec(),
+            Literal::Uuid(bytes) => bytes.to_vec(),
+            Literal::Fixed(bytes) | Literal::Binary(bytes) => bytes.clone(),
+            Literal::Decimal { unscaled, .. } => encode_decimal(*unscaled),
+        }
+    }
+
+    /// Converts a JSON-decoded partition value (as produced by deserializing an Avro
+    /// `DataFile.partition` record into a [`serde_json::Value`]) into a typed [`Literal`],
+    /// given the partition field's type. Returns `None` for a JSON null.
+    pub fn try_from_json(r#type: &Type, value: &serde_json::Value) -> Result<Option<Self>> {
+        if value.is_null() {
+            return Ok(None);
+        }
+        let primitive = match r#type {
+            Type::Primitive(primitive) => primitive,
+            other => bail!("{other:?} has no literal representation"),
+        };
+        Ok(Some(match primitive {
+            PrimitiveType::Boolean => Literal::Boolean(
+                value
+                    .as_bool()
+                    .ok_or_else(|| anyhow!("expected a boolean, got {value}"))?,
+            ),
+            PrimitiveType::Int => Literal::Int(as_i64(value)? as i32),
+            PrimitiveType::Date => Literal::Date(as_i64(value)? as i32),
+            PrimitiveType::Long => Literal::Long(as_i64(value)?),
+            PrimitiveType::Time => Literal::Time(as_i64(value)?),
+            PrimitiveType::Timestamp => Literal::Timestamp(as_i64(value)?),
+            PrimitiveType::Timestamptz => Literal::Timestamptz(as_i64(value)?),
+            PrimitiveType::Float => Literal::Float(as_f64(value)? as f32),
+            PrimitiveType::Double => Literal::Double(as_f64(value)?),
+            PrimitiveType::String => Literal::String(
+                value
+                    .as_str()
+                    .ok_or_else(|| anyhow!("expected a string, got {value}"))?
+                    .to_string(),
+            ),
+            PrimitiveType::Decimal { scale, .. } => {
+                let s = value
+                    .as_str()
+                    .ok_or_else(|| anyhow!("expected a decimal string, got {value}"))?;
+                Literal::Decimal {
+                    unscaled: parse_decimal_string(s, *scale)?,
+                    scale: *scale,
+                }
+            }
+            PrimitiveType::Uuid | PrimitiveType::Fixed(_) | PrimitiveType::Binary => {
+                bail!("{primitive:?} partition values are not yet supported")
+            }
+        }))
+    }
+}
+
+fn as_i64(value: &serde_json::Value) -> Result<i64> {
+    value
+        .as_i64()
+        .ok_or_else(|| anyhow!("expected an integer, got {value}"))
+}
+
+fn as_f64(value: &serde_json::Value) -> Result<f64> {
+    value
+        .as_f64()
+        .ok_or_else(|| anyhow!("expected a number, got {value}"))
+}
+
+/// Parses a plain decimal string such as `"14.20"` into its unscaled value for the given
+/// `scale`, e.g. `("14.20", 2)` -> `1420`.
+fn parse_decimal_string(s: &str, scale: u32) -> Result<i128> {
+    let negative = s.starts_with('-');
+    let unsigned = s.trim_start_matches('-');
+    let (int_part, frac_part) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if frac_part.len() as u32 > scale {
+        bail!("decimal string '{s}' has more than {scale} fractional digits");
+    }
+    let mut digits = String::from(int_part);
+    digits.push_str(frac_part);
+    digits.extend(std::iter::repeat('0').take(scale as usize - frac_part.len()));
+    let magnitude: i128 = digits
+        .parse()
+        .map_err(|_| anyhow!("invalid decimal string: '{s}'"))?;
+    Ok(if negative { -magnitude } else { magnitude })
+}
+
+/// Encodes a value into a big-endian, two's-complement, minimum-length byte array.
+fn encode_decimal(value: i128) -> Vec<u8> {
+    let full = value.to_be_bytes();
+    let sign_byte = if value < 0 { 0xffu8 } else { 0x00u8 };
+    let mut start = 0;
+    while start < 15 && full[start] == sign_byte && (full[start + 1] ^ sign_byte) & 0x80 == 0 {
+        start += 1;
+    }
+    full[start..].to_vec()
+}
+
+/// Decodes a big-endian, two's-complement, minimum-length byte array into its value.
+fn decode_decimal(bytes: &[u8]) -> Result<i128> {
+    if bytes.is_empty() || bytes.len() > 16 {
+        bail!(
+            "decimal unscaled value must be between 1 and 16 bytes, got {}",
+            bytes.len()
+        );
+    }
+    let sign_extension = if bytes[0] & 0x80 != 0 { 0xff } else { 0x00 };
+    let mut buf = [sign_extension; 16];
+    buf[16 - bytes.len()..].copy_from_slice(bytes);
+    Ok(i128::from_be_bytes(buf))
+}
+
+/// Orders two literals of the same variant. Comparing literals of different variants is not
+/// meaningful and returns [`std::cmp::Ordering::Equal`]; callers only compare values already
+/// known to share a column's type.
+pub fn compare(a: &Literal, b: &Literal) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+    match (a, b) {
+        (Literal::Boolean(x), Literal::Boolean(y)) => x.cmp(y),
+        (Literal::Int(x), Literal::Int(y)) => x.cmp(y),
+        (Literal::Long(x), Literal::Long(y)) => x.cmp(y),
+        (Literal::Float(x), Literal::Float(y)) => x.partial_cmp(y).unwrap_or(Ordering::Equal),
+        (Literal::Double(x), Literal::Double(y)) => x.partial_cmp(y).unwrap_or(Ordering::Equal),
+        (Literal::Date(x), Literal::Date(y)) => x.cmp(y),
+        (Literal::Time(x), Literal::Time(y)) => x.cmp(y),
+        (Literal::Timestamp(x), Literal::Timestamp(y)) => x.cmp(y),
+        (Literal::Timestamptz(x), Literal::Timestamptz(y)) => x.cmp(y),
+        (Literal::String(x), Literal::String(y)) => x.cmp(y),
+        (Literal::Uuid(x), Literal::Uuid(y)) => x.cmp(y),
+        (Literal::Fixed(x), Literal::Fixed(y)) => x.cmp(y),
+        (Literal::Binary(x), Literal::Binary(y)) => x.cmp(y),
+        (Literal::Decimal { unscaled: x, .. }, Literal::Decimal { unscaled: y, .. }) => x.cmp(y),
+        _ => Ordering::Equal,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::types::PrimitiveType;
+
+    fn primitive(p: PrimitiveType) -> Type {
+        Type::Primitive(p)
+    }
+
+    #[test]
+    fn test_decode_primitives() {
+        assert_eq!(
+            Literal::try_from_bytes(&primitive(PrimitiveType::Boolean), &[0x01]).unwrap(),
+            Literal::Boolean(true)
+        );
+        assert_eq!(
+            Literal::try_from_bytes(&primitive(PrimitiveType::Int), &34_i32.to_le_bytes()).unwrap(),
+            Literal::Int(34)
+        );
+        assert_eq!(
+            Literal::try_from_bytes(&primitive(PrimitiveType::Long), &(-34_i64).to_le_bytes())
+                .unwrap(),
+            Literal::Long(-34)
+        );
+        assert_eq!(
+            Literal::try_from_bytes(&primitive(PrimitiveType::String), "iceberg".as_bytes())
+                .unwrap(),
+            Literal::String("iceberg".to_string())
+        );
+    }
+
+    #[test]
+    fn test_decode_decimal() {
+        // decimal(9, 2) value 14.20 -> unscaled 1420, minimum two's-complement bytes 0x05 0x8C
+        let value = Literal::try_from_bytes(
+            &primitive(PrimitiveType::Decimal {
+                precision: 9,
+                scale: 2,
+            }),
+            &[0x05, 0x8C],
+        )
+        .unwrap();
+        assert_eq!(
+            value,
+            Literal::Decimal {
+                unscaled: 1420,
+                scale: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_negative_decimal() {
+        // -1 as a minimum-length two's-complement byte array is a single 0xFF byte
+        let value = Literal::try_from_bytes(
+            &primitive(PrimitiveType::Decimal {
+                precision: 9,
+                scale: 0,
+            }),
+            &[0xFF],
+        )
+        .unwrap();
+        assert_eq!(
+            value,
+            Literal::Decimal {
+                unscaled: -1,
+                scale: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_uuid() {
+        let bytes: [u8; 16] = [
+            0xf7, 0x9c, 0x3e, 0x09, 0x67, 0x7c, 0x4b, 0xbd, 0xa4, 0x79, 0x3f, 0x34, 0x9c, 0xb7,
+            0x85, 0xe7,
+        ];
+        let value = Literal::try_from_bytes(&primitive(PrimitiveType::Uuid), &bytes).unwrap();
+        assert_eq!(value, Literal::Uuid(bytes));
+    }
+}
@@ -0,0 +1,91 @@
+//! Splits a pruned scan's manifest entries into data files and the delete files that apply to
+//! them, so a reader can apply [merge-on-read deletes](https://iceberg.apache.org/spec/#scan-planning)
+//! without re-reading every delete file for every data file.
+
+use crate::model::manifest::{DataFile, DataFileContent, ManifestEntry};
+
+/// One data file paired with the delete files that apply to it.
+pub struct PlannedDataFile<'a> {
+    /// the data file to read
+    pub data_file: &'a DataFile,
+    /// position delete files that apply to `data_file`
+    pub position_deletes: Vec<&'a DataFile>,
+    /// equality delete files that apply to `data_file`
+    pub equality_deletes: Vec<&'a DataFile>,
+}
+
+/// Splits `entries` (typically the already-pruned entries of a scan) into data files and the
+/// delete files that apply to each. A delete file applies to a data file only if the delete's
+/// sequence number is strictly greater than the data file's own, since a delete can only remove
+/// rows from data written before it.
+pub fn plan_deletes(entries: &[ManifestEntry]) -> Vec<PlannedDataFile<'_>> {
+    let mut data_entries = Vec::new();
+    let mut position_delete_entries = Vec::new();
+    let mut equality_delete_entries = Vec::new();
+    for entry in entries {
+        match entry.data_file.content() {
+            DataFileContent::Data => data_entries.push(entry),
+            DataFileContent::PositionDeletes => position_delete_entries.push(entry),
+            DataFileContent::EqualityDeletes => equality_delete_entries.push(entry),
+        }
+    }
+
+    data_entries
+        .into_iter()
+        .map(|data_entry| {
+            let data_sequence_number = data_entry.sequence_number.unwrap_or(0);
+            PlannedDataFile {
+                data_file: &data_entry.data_file,
+                position_deletes: applicable_deletes(&position_delete_entries, data_sequence_number),
+                equality_deletes: applicable_deletes(&equality_delete_entries, data_sequence_number),
+            }
+        })
+        .collect()
+}
+
+/// The data files of `delete_entries` whose sequence number is greater than `data_sequence_number`.
+fn applicable_deletes<'a>(delete_entries: &[&'a ManifestEntry], data_sequence_number: i64) -> Vec<&'a DataFile> {
+    delete_entries
+        .iter()
+        .filter(|delete_entry| delete_entry.sequence_number.unwrap_or(0) > data_sequence_number)
+        .map(|delete_entry| &delete_entry.data_file)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn entry(content: DataFileContent, sequence_number: i64) -> ManifestEntry {
+        let data_file: DataFile = serde_json::from_value(serde_json::json!({
+            "file_path": "s3://bucket/data/a.parquet",
+            "file_format": "PARQUET",
+            "partition": {},
+            "record_count": 1,
+            "file_size_in_bytes": 1,
+            "content": content as i32,
+            "sort_order_id": 0,
+        }))
+        .unwrap();
+        ManifestEntry {
+            status: 1,
+            snapshot_id: Some(1),
+            data_file,
+            sequence_number: Some(sequence_number),
+            file_sequence_number: Some(sequence_number),
+        }
+    }
+
+    #[test]
+    fn test_plan_deletes_only_applies_to_older_data_files() {
+        let entries = vec![
+            entry(DataFileContent::Data, 1),
+            entry(DataFileContent::Data, 3),
+            entry(DataFileContent::PositionDeletes, 2),
+        ];
+        let planned = plan_deletes(&entries);
+        assert_eq!(planned.len(), 2);
+        assert_eq!(planned[0].position_deletes.len(), 1);
+        assert_eq!(planned[1].position_deletes.len(), 0);
+    }
+}
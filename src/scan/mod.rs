@@ -0,0 +1,11 @@
+//! Scan planning: pruning the manifests and data files a table scan needs to read, using the
+//! summary statistics already stored in manifests and manifest lists instead of opening every
+//! file up front.
+
+pub mod deletes;
+pub mod planning;
+pub mod predicate;
+
+pub use deletes::{plan_deletes, PlannedDataFile};
+pub use planning::{prune_data_files, prune_manifests};
+pub use predicate::{BinaryOp, Predicate};
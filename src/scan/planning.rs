@@ -0,0 +1,334 @@
+//! Prunes manifests and data files against a [`Predicate`] using the summary statistics
+//! already stored in the manifest list and manifests, without opening every Avro or Parquet
+//! file.
+
+use std::cmp::Ordering;
+
+use anyhow::Result;
+
+use crate::model::manifest::{DataFile, FieldSummary, ManifestFile};
+use crate::model::schema::Schema;
+use crate::model::types::Type;
+use crate::model::values::{self, Literal};
+
+use super::predicate::{BinaryOp, Predicate};
+
+/// Filters `manifests` down to the ones whose partition ranges cannot be ruled out by
+/// `predicate`, using each manifest's [`FieldSummary`] partition bounds.
+///
+/// `partition_field_ids` and `partition_types` describe the partition spec in the same order
+/// as `ManifestFile::partitions`.
+pub fn prune_manifests<'a>(
+    manifests: &'a [ManifestFile],
+    predicate: &Predicate,
+    partition_field_ids: &[i32],
+    partition_types: &[Type],
+) -> Result<Vec<&'a ManifestFile>> {
+    let predicate = predicate.clone().into_nnf();
+    manifests
+        .iter()
+        .filter_map(
+            |manifest| match might_match_partitions(
+                &predicate,
+                partition_field_ids,
+                partition_types,
+                &manifest.partitions,
+            ) {
+                Ok(true) => Some(Ok(manifest)),
+                Ok(false) => None,
+                Err(e) => Some(Err(e)),
+            },
+        )
+        .collect()
+}
+
+/// Filters `data_files` (typically all the entries of one already-opened manifest) down to
+/// the ones whose column bounds cannot be ruled out by `predicate`. A surviving file's
+/// `split_offsets` can be used to parallelize reading it by row group.
+///
+/// A file whose relevant bound can't be decoded (for example because schema evolution has
+/// since dropped the column the predicate references) is conservatively kept rather than
+/// aborting the scan for every other file.
+pub fn prune_data_files<'a>(data_files: &'a [DataFile], predicate: &Predicate, schema: &Schema) -> Vec<&'a DataFile> {
+    let predicate = predicate.clone().into_nnf();
+    data_files
+        .iter()
+        .filter(|data_file| might_match_data_file(&predicate, data_file, schema).unwrap_or(true))
+        .collect()
+}
+
+fn might_match_partitions(
+    predicate: &Predicate,
+    field_ids: &[i32],
+    types: &[Type],
+    summaries: &[FieldSummary],
+) -> Result<bool> {
+    Ok(match predicate {
+        Predicate::AlwaysTrue => true,
+        Predicate::AlwaysFalse => false,
+        Predicate::Not(_) => unreachable!("predicate must be in negation normal form"),
+        Predicate::And(a, b) => {
+            might_match_partitions(a, field_ids, types, summaries)?
+                && might_match_partitions(b, field_ids, types, summaries)?
+        }
+        Predicate::Or(a, b) => {
+            might_match_partitions(a, field_ids, types, summaries)?
+                || might_match_partitions(b, field_ids, types, summaries)?
+        }
+        // A summary only records that *some* partition had a null/NaN value, never that
+        // *every* partition did, so NOT NULL/NOT NAN can never be ruled out from it alone.
+        Predicate::IsNull { field_id } => field_index(field_ids, *field_id)
+            .map(|i| summaries[i].contains_null)
+            .unwrap_or(true),
+        Predicate::NotNull { .. } => true,
+        Predicate::IsNan { field_id } => field_index(field_ids, *field_id)
+            .map(|i| summaries[i].contains_nan)
+            .unwrap_or(true),
+        Predicate::NotNan { .. } => true,
+        Predicate::Binary {
+            op,
+            field_id,
+            literal,
+        } => match field_index(field_ids, *field_id) {
+            None => true,
+            Some(i) => {
+                let (lower, upper) = summaries[i].typed_bounds(&types[i])?;
+                might_match_range(*op, literal, lower.as_ref(), upper.as_ref())
+            }
+        },
+    })
+}
+
+fn might_match_data_file(predicate: &Predicate, data_file: &DataFile, schema: &Schema) -> Result<bool> {
+    Ok(match predicate {
+        Predicate::AlwaysTrue => true,
+        Predicate::AlwaysFalse => false,
+        Predicate::Not(_) => unreachable!("predicate must be in negation normal form"),
+        Predicate::And(a, b) => {
+            might_match_data_file(a, data_file, schema)? && might_match_data_file(b, data_file, schema)?
+        }
+        Predicate::Or(a, b) => {
+            might_match_data_file(a, data_file, schema)? || might_match_data_file(b, data_file, schema)?
+        }
+        Predicate::IsNull { field_id } => lookup(data_file.null_value_counts(), *field_id)
+            .map(|count| count > 0)
+            .unwrap_or(true),
+        Predicate::NotNull { field_id } => {
+            match (
+                lookup(data_file.value_counts(), *field_id),
+                lookup(data_file.null_value_counts(), *field_id),
+            ) {
+                (Some(total), Some(nulls)) => nulls < total,
+                _ => true,
+            }
+        }
+        Predicate::IsNan { field_id } => lookup(data_file.nan_value_counts(), *field_id)
+            .map(|count| count > 0)
+            .unwrap_or(true),
+        // A NaN count alone can't prove every value is NaN.
+        Predicate::NotNan { .. } => true,
+        Predicate::Binary {
+            op,
+            field_id,
+            literal,
+        } => {
+            let lower = decode_bound(data_file.lower_bounds(), *field_id, schema)?;
+            let upper = decode_bound(data_file.upper_bounds(), *field_id, schema)?;
+            might_match_range(*op, literal, lower.as_ref(), upper.as_ref())
+        }
+    })
+}
+
+/// Decodes the bound for `field_id` alone out of a `DataFile`'s `lower_bounds`/`upper_bounds`,
+/// rather than every column id the file happens to carry a bound for -- a file written before
+/// a schema change may carry bounds for columns `schema` no longer has.
+fn decode_bound(bounds: &[(i32, Vec<u8>)], field_id: i32, schema: &Schema) -> Result<Option<Literal>> {
+    let Some((_, bytes)) = bounds.iter().find(|(id, _)| *id == field_id) else {
+        return Ok(None);
+    };
+    let field_type = schema
+        .field_type(field_id)
+        .ok_or_else(|| anyhow::anyhow!("schema has no field with id {field_id}"))?;
+    Ok(Some(Literal::try_from_bytes(field_type, bytes)?))
+}
+
+fn field_index(ids: &[i32], field_id: i32) -> Option<usize> {
+    ids.iter().position(|id| *id == field_id)
+}
+
+fn lookup(counts: &[(i32, i64)], field_id: i32) -> Option<i64> {
+    counts
+        .iter()
+        .find(|(id, _)| *id == field_id)
+        .map(|(_, count)| *count)
+}
+
+/// Whether a range `[lower, upper]` (either bound `None` meaning unknown) can contain a value
+/// satisfying `op literal`.
+fn might_match_range(op: BinaryOp, literal: &Literal, lower: Option<&Literal>, upper: Option<&Literal>) -> bool {
+    match op {
+        BinaryOp::Eq => {
+            let above_lower = lower
+                .map(|l| values::compare(l, literal) != Ordering::Greater)
+                .unwrap_or(true);
+            let below_upper = upper
+                .map(|u| values::compare(u, literal) != Ordering::Less)
+                .unwrap_or(true);
+            above_lower && below_upper
+        }
+        // Excluding a single value from a range essentially never empties it.
+        BinaryOp::NotEq => true,
+        BinaryOp::Lt => lower
+            .map(|l| values::compare(l, literal) == Ordering::Less)
+            .unwrap_or(true),
+        BinaryOp::LtEq => lower
+            .map(|l| values::compare(l, literal) != Ordering::Greater)
+            .unwrap_or(true),
+        BinaryOp::Gt => upper
+            .map(|u| values::compare(u, literal) == Ordering::Greater)
+            .unwrap_or(true),
+        BinaryOp::GtEq => upper
+            .map(|u| values::compare(u, literal) != Ordering::Less)
+            .unwrap_or(true),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::manifest::ManifestContent;
+    use crate::model::types::{PrimitiveType, StructField};
+
+    fn field_summary(lower: i32, upper: i32) -> FieldSummary {
+        FieldSummary {
+            contains_null: false,
+            contains_nan: false,
+            lower_bound: lower.to_le_bytes().to_vec(),
+            upper_bound: upper.to_le_bytes().to_vec(),
+        }
+    }
+
+    fn manifest_file(partitions: Vec<FieldSummary>) -> ManifestFile {
+        ManifestFile {
+            manifest_path: "s3://bucket/metadata/m1.avro".to_string(),
+            manifest_length: 10,
+            added_snapshot_id: 1,
+            sequence_number: Some(1),
+            min_sequence_number: Some(1),
+            added_files_count: Some(1),
+            existing_files_count: Some(0),
+            deleted_fields_count: Some(0),
+            partitions,
+            added_rows_count: Some(1),
+            existing_rows_count: Some(0),
+            deleted_rows_count: Some(0),
+            partition_spec_id: 0,
+            content: Some(ManifestContent::Data),
+        }
+    }
+
+    #[test]
+    fn test_prune_manifests_by_partition_bounds() {
+        let manifests = vec![
+            manifest_file(vec![field_summary(1, 10)]),
+            manifest_file(vec![field_summary(100, 200)]),
+        ];
+        let predicate = Predicate::Binary {
+            op: BinaryOp::Eq,
+            field_id: 1000,
+            literal: Literal::Int(5),
+        };
+        let surviving = prune_manifests(&manifests, &predicate, &[1000], &[Type::Primitive(PrimitiveType::Int)])
+            .unwrap();
+        assert_eq!(surviving.len(), 1);
+        assert_eq!(surviving[0].partitions[0].lower_bound, 1i32.to_le_bytes().to_vec());
+    }
+
+    #[test]
+    fn test_prune_manifests_unknown_field_is_conservative() {
+        let manifests = vec![manifest_file(vec![field_summary(1, 10)])];
+        let predicate = Predicate::Binary {
+            op: BinaryOp::Eq,
+            field_id: 9999,
+            literal: Literal::Int(5),
+        };
+        let surviving = prune_manifests(&manifests, &predicate, &[1000], &[Type::Primitive(PrimitiveType::Int)])
+            .unwrap();
+        assert_eq!(surviving.len(), 1);
+    }
+
+    #[test]
+    fn test_prune_data_files_by_column_bounds() {
+        let schema = Schema {
+            type_tag: "struct".to_string(),
+            schema_id: 0,
+            identifier_field_ids: vec![],
+            fields: vec![StructField {
+                id: 1,
+                name: "amount".to_string(),
+                required: true,
+                field_type: Type::Primitive(PrimitiveType::Int),
+                doc: None,
+            }],
+        };
+        let make_file = |lower: i32, upper: i32| -> DataFile {
+            serde_json::from_value(serde_json::json!({
+                "file_path": "s3://bucket/data/a.parquet",
+                "file_format": "PARQUET",
+                "partition": {},
+                "record_count": 1,
+                "file_size_in_bytes": 1,
+                "content": 0,
+                "sort_order_id": 0,
+                "lower_bounds": [{"key": 1, "value": lower.to_le_bytes()}],
+                "upper_bounds": [{"key": 1, "value": upper.to_le_bytes()}],
+            }))
+            .unwrap()
+        };
+        let data_files = vec![make_file(1, 10), make_file(100, 200)];
+        let predicate = Predicate::Binary {
+            op: BinaryOp::Gt,
+            field_id: 1,
+            literal: Literal::Int(50),
+        };
+        let surviving = prune_data_files(&data_files, &predicate, &schema);
+        assert_eq!(surviving.len(), 1);
+    }
+
+    #[test]
+    fn test_prune_data_files_keeps_file_with_bound_for_dropped_column() {
+        let schema = Schema {
+            type_tag: "struct".to_string(),
+            schema_id: 0,
+            identifier_field_ids: vec![],
+            fields: vec![StructField {
+                id: 1,
+                name: "amount".to_string(),
+                required: true,
+                field_type: Type::Primitive(PrimitiveType::Int),
+                doc: None,
+            }],
+        };
+        // column 2 no longer exists in `schema` (e.g. dropped since this file was written),
+        // but the predicate only references column 1, so pruning must not error out on it.
+        let data_file: DataFile = serde_json::from_value(serde_json::json!({
+            "file_path": "s3://bucket/data/a.parquet",
+            "file_format": "PARQUET",
+            "partition": {},
+            "record_count": 1,
+            "file_size_in_bytes": 1,
+            "content": 0,
+            "sort_order_id": 0,
+            "lower_bounds": [{"key": 1, "value": 1i32.to_le_bytes()}, {"key": 2, "value": 1i32.to_le_bytes()}],
+            "upper_bounds": [{"key": 1, "value": 10i32.to_le_bytes()}, {"key": 2, "value": 10i32.to_le_bytes()}],
+        }))
+        .unwrap();
+        let predicate = Predicate::Binary {
+            op: BinaryOp::Eq,
+            field_id: 1,
+            literal: Literal::Int(5),
+        };
+        let surviving = prune_data_files(&[data_file], &predicate, &schema);
+        assert_eq!(surviving.len(), 1);
+    }
+}
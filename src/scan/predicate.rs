@@ -0,0 +1,162 @@
+//! A minimal boolean predicate over a table's fields, identified by field id. Used to prune
+//! manifests and data files during scan planning without opening every Avro or Parquet file.
+
+use crate::model::values::Literal;
+
+/// A comparison operator usable in a [`Predicate::Binary`] leaf.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryOp {
+    /// `=`
+    Eq,
+    /// `!=`
+    NotEq,
+    /// `<`
+    Lt,
+    /// `<=`
+    LtEq,
+    /// `>`
+    Gt,
+    /// `>=`
+    GtEq,
+}
+
+impl BinaryOp {
+    /// The operator satisfied by exactly the rows this one rejects.
+    pub fn negate(self) -> Self {
+        match self {
+            BinaryOp::Eq => BinaryOp::NotEq,
+            BinaryOp::NotEq => BinaryOp::Eq,
+            BinaryOp::Lt => BinaryOp::GtEq,
+            BinaryOp::LtEq => BinaryOp::Gt,
+            BinaryOp::Gt => BinaryOp::LtEq,
+            BinaryOp::GtEq => BinaryOp::Lt,
+        }
+    }
+}
+
+/// A predicate over a table's fields. Boolean combinators compose leaves that compare a
+/// field's value, check its nullability, or check for NaN.
+#[derive(Clone, Debug)]
+pub enum Predicate {
+    /// matches every row
+    AlwaysTrue,
+    /// matches no row
+    AlwaysFalse,
+    /// logical negation
+    Not(Box<Predicate>),
+    /// logical conjunction
+    And(Box<Predicate>, Box<Predicate>),
+    /// logical disjunction
+    Or(Box<Predicate>, Box<Predicate>),
+    /// `field_id IS NULL`
+    IsNull {
+        /// id of the field being tested
+        field_id: i32,
+    },
+    /// `field_id IS NOT NULL`
+    NotNull {
+        /// id of the field being tested
+        field_id: i32,
+    },
+    /// `field_id IS NAN`
+    IsNan {
+        /// id of the field being tested
+        field_id: i32,
+    },
+    /// `field_id IS NOT NAN`
+    NotNan {
+        /// id of the field being tested
+        field_id: i32,
+    },
+    /// a comparison between a field's value and a literal
+    Binary {
+        /// the comparison operator
+        op: BinaryOp,
+        /// id of the field being compared
+        field_id: i32,
+        /// the literal compared against
+        literal: Literal,
+    },
+}
+
+impl Predicate {
+    /// Rewrites this predicate into [negation normal form](https://en.wikipedia.org/wiki/Negation_normal_form):
+    /// pushes every [`Predicate::Not`] down to the leaves by flipping the operator it negates,
+    /// so evaluators never need to handle `Not` directly.
+    pub fn into_nnf(self) -> Predicate {
+        self.into_nnf_negated(false)
+    }
+
+    fn into_nnf_negated(self, negated: bool) -> Predicate {
+        match self {
+            Predicate::AlwaysTrue => {
+                if negated {
+                    Predicate::AlwaysFalse
+                } else {
+                    Predicate::AlwaysTrue
+                }
+            }
+            Predicate::AlwaysFalse => {
+                if negated {
+                    Predicate::AlwaysTrue
+                } else {
+                    Predicate::AlwaysFalse
+                }
+            }
+            Predicate::Not(p) => p.into_nnf_negated(!negated),
+            Predicate::And(a, b) => {
+                let (a, b) = (a.into_nnf_negated(negated), b.into_nnf_negated(negated));
+                if negated {
+                    Predicate::Or(Box::new(a), Box::new(b))
+                } else {
+                    Predicate::And(Box::new(a), Box::new(b))
+                }
+            }
+            Predicate::Or(a, b) => {
+                let (a, b) = (a.into_nnf_negated(negated), b.into_nnf_negated(negated));
+                if negated {
+                    Predicate::And(Box::new(a), Box::new(b))
+                } else {
+                    Predicate::Or(Box::new(a), Box::new(b))
+                }
+            }
+            Predicate::IsNull { field_id } => {
+                if negated {
+                    Predicate::NotNull { field_id }
+                } else {
+                    Predicate::IsNull { field_id }
+                }
+            }
+            Predicate::NotNull { field_id } => {
+                if negated {
+                    Predicate::IsNull { field_id }
+                } else {
+                    Predicate::NotNull { field_id }
+                }
+            }
+            Predicate::IsNan { field_id } => {
+                if negated {
+                    Predicate::NotNan { field_id }
+                } else {
+                    Predicate::IsNan { field_id }
+                }
+            }
+            Predicate::NotNan { field_id } => {
+                if negated {
+                    Predicate::IsNan { field_id }
+                } else {
+                    Predicate::NotNan { field_id }
+                }
+            }
+            Predicate::Binary {
+                op,
+                field_id,
+                literal,
+            } => Predicate::Binary {
+                op: if negated { op.negate() } else { op },
+                field_id,
+                literal,
+            },
+        }
+    }
+}
@@ -0,0 +1,262 @@
+//! Writes [`ManifestEntry`] records to an Avro manifest file, tracking the running file/row
+//! counts and partition bounds the [`ManifestListWriter`](super::manifest_list::ManifestListWriter)
+//! needs for this manifest's entry in the manifest list.
+
+use anyhow::{anyhow, Result};
+use apache_avro::Writer as AvroWriter;
+
+use crate::model::manifest::{DataFileContent, FieldSummary, FormatVersion, ManifestContent, ManifestEntry};
+use crate::model::schema::Schema;
+use crate::model::types::Type;
+use crate::model::values::Literal;
+
+/// Running per-partition-field summary, accumulated one [`ManifestEntry`] at a time.
+#[derive(Clone, Default)]
+struct PartitionSummary {
+    contains_null: bool,
+    contains_nan: bool,
+    bounds: Option<(Literal, Literal)>,
+}
+
+/// Writes manifest entries to an Avro file, computing the [`FieldSummary`] partition bounds
+/// and added/existing/deleted file and row counts that belong in this manifest's
+/// [`ManifestFile`](crate::model::manifest::ManifestFile) entry in the manifest list.
+pub struct ManifestWriter<'a> {
+    inner: AvroWriter<'a, Vec<u8>>,
+    partition_fields: Vec<(String, Type)>,
+    partition_summaries: Vec<PartitionSummary>,
+    added_files_count: i32,
+    existing_files_count: i32,
+    deleted_files_count: i32,
+    added_rows_count: i64,
+    existing_rows_count: i64,
+    deleted_rows_count: i64,
+    content: Option<ManifestContent>,
+}
+
+const STATUS_EXISTING: i32 = 0;
+const STATUS_ADDED: i32 = 1;
+const STATUS_DELETED: i32 = 2;
+
+impl<'a> ManifestWriter<'a> {
+    /// Creates a writer for a new manifest file written with the given `format_version` against
+    /// `schema` (built with [`parse_manifest_entry_schema`](super::schema::parse_manifest_entry_schema)
+    /// from the same partition fields passed here as `(name, type)` pairs, in partition spec
+    /// order).
+    ///
+    /// `table_schema` and `partition_spec` are the table schema and partition spec this manifest
+    /// was written against (`partition_spec` pre-built as the spec's array-of-fields JSON, since
+    /// this crate has no `PartitionSpec` model type yet); they're stamped into the manifest's
+    /// `schema`/`partition-spec`/`partition-spec-id` metadata, as the spec requires.
+    pub fn new(
+        schema: &'a apache_avro::Schema,
+        format_version: FormatVersion,
+        partition_fields: Vec<(String, Type)>,
+        table_schema: &Schema,
+        partition_spec: serde_json::Value,
+        partition_spec_id: i32,
+    ) -> Result<Self> {
+        let mut inner = AvroWriter::new(schema, Vec::new());
+        inner
+            .add_user_metadata(
+                "format-version".to_string(),
+                (format_version as u8).to_string(),
+            )
+            .map_err(|e| anyhow!("failed to set manifest format-version: {e:?}"))?;
+        inner
+            .add_user_metadata(
+                "schema".to_string(),
+                serde_json::to_string(table_schema)
+                    .map_err(|e| anyhow!("failed to encode manifest schema metadata: {e:?}"))?,
+            )
+            .map_err(|e| anyhow!("failed to set manifest schema metadata: {e:?}"))?;
+        inner
+            .add_user_metadata("schema-id".to_string(), table_schema.schema_id.to_string())
+            .map_err(|e| anyhow!("failed to set manifest schema-id metadata: {e:?}"))?;
+        inner
+            .add_user_metadata(
+                "partition-spec".to_string(),
+                serde_json::to_string(&partition_spec)
+                    .map_err(|e| anyhow!("failed to encode manifest partition-spec metadata: {e:?}"))?,
+            )
+            .map_err(|e| anyhow!("failed to set manifest partition-spec metadata: {e:?}"))?;
+        inner
+            .add_user_metadata("partition-spec-id".to_string(), partition_spec_id.to_string())
+            .map_err(|e| anyhow!("failed to set manifest partition-spec-id metadata: {e:?}"))?;
+
+        let partition_count = partition_fields.len();
+        Ok(Self {
+            inner,
+            partition_fields,
+            partition_summaries: (0..partition_count)
+                .map(|_| PartitionSummary::default())
+                .collect(),
+            added_files_count: 0,
+            existing_files_count: 0,
+            deleted_files_count: 0,
+            added_rows_count: 0,
+            existing_rows_count: 0,
+            deleted_rows_count: 0,
+            content: None,
+        })
+    }
+
+    /// Writes one manifest entry, folding its `data_file.partition` value and record count
+    /// into the running statistics.
+    ///
+    /// Errors if `entry.data_file` fails [`DataFile::validate`](crate::model::manifest::DataFile::validate),
+    /// or if its content (data vs. deletes) conflicts with an entry already written to this
+    /// manifest — a manifest may only ever reference one or the other.
+    pub fn write(&mut self, entry: &ManifestEntry) -> Result<()> {
+        entry.data_file.validate()?;
+        let entry_content = match entry.data_file.content() {
+            DataFileContent::Data => ManifestContent::Data,
+            DataFileContent::PositionDeletes | DataFileContent::EqualityDeletes => ManifestContent::Deletes,
+        };
+        match self.content {
+            None => {}
+            Some(existing) if existing == entry_content => {}
+            Some(_) => anyhow::bail!("manifest cannot mix data files and delete files"),
+        }
+        if !matches!(entry.status, STATUS_ADDED | STATUS_EXISTING | STATUS_DELETED) {
+            bail_unknown_status(entry.status)?;
+        }
+        // Compute the would-be partition fold before mutating any running statistics, so a
+        // failure here (e.g. an unsupported partition value) leaves `self` exactly as it was
+        // instead of desyncing the counts from what's actually been appended to the Avro file.
+        let partition_summaries = self.folded_partition_summaries(entry)?;
+
+        if self.content.is_none() {
+            // `content` isn't known until this, the first entry — stamp it now, before the
+            // Avro header is flushed by the `append_ser` call below, since `add_user_metadata`
+            // has no effect once the writer has written its first record.
+            self.inner
+                .add_user_metadata("content".to_string(), entry_content.as_str().to_string())
+                .map_err(|e| anyhow!("failed to set manifest content metadata: {e:?}"))?;
+        }
+        self.inner
+            .append_ser(entry)
+            .map_err(|e| anyhow!("failed to write manifest entry: {e:?}"))?;
+
+        self.content = Some(entry_content);
+        match entry.status {
+            STATUS_ADDED => {
+                self.added_files_count += 1;
+                self.added_rows_count += entry.data_file.record_count();
+            }
+            STATUS_EXISTING => {
+                self.existing_files_count += 1;
+                self.existing_rows_count += entry.data_file.record_count();
+            }
+            STATUS_DELETED => {
+                self.deleted_files_count += 1;
+                self.deleted_rows_count += entry.data_file.record_count();
+            }
+            _ => unreachable!("status already validated above"),
+        }
+        self.partition_summaries = partition_summaries;
+        Ok(())
+    }
+
+    /// Computes the partition summaries that folding `entry` into `self.partition_summaries`
+    /// would produce, without mutating `self` — so a caller can apply the fold only once it
+    /// knows the entry will actually be written.
+    fn folded_partition_summaries(&self, entry: &ManifestEntry) -> Result<Vec<PartitionSummary>> {
+        let partition = entry.data_file.partition();
+        let mut summaries = self.partition_summaries.clone();
+        for (i, (name, field_type)) in self.partition_fields.iter().enumerate() {
+            let value = partition.get(name);
+            let literal = match value {
+                Some(value) => Literal::try_from_json(field_type, value)?,
+                None => None,
+            };
+            let summary = &mut summaries[i];
+            match literal {
+                None => summary.contains_null = true,
+                Some(Literal::Float(v)) if v.is_nan() => summary.contains_nan = true,
+                Some(Literal::Double(v)) if v.is_nan() => summary.contains_nan = true,
+                Some(literal) => {
+                    summary.bounds = Some(match summary.bounds.take() {
+                        None => (literal.clone(), literal),
+                        Some((lower, upper)) => (
+                            if crate::model::values::compare(&literal, &lower).is_lt() {
+                                literal.clone()
+                            } else {
+                                lower
+                            },
+                            if crate::model::values::compare(&literal, &upper).is_gt() {
+                                literal
+                            } else {
+                                upper
+                            },
+                        ),
+                    });
+                }
+            }
+        }
+        Ok(summaries)
+    }
+
+    /// The partition [`FieldSummary`] computed from every entry written so far, in partition
+    /// spec order.
+    pub fn field_summaries(&self) -> Vec<FieldSummary> {
+        self.partition_summaries
+            .iter()
+            .map(|summary| FieldSummary {
+                contains_null: summary.contains_null,
+                contains_nan: summary.contains_nan,
+                lower_bound: summary
+                    .bounds
+                    .as_ref()
+                    .map(|(lower, _)| lower.to_bytes())
+                    .unwrap_or_default(),
+                upper_bound: summary
+                    .bounds
+                    .as_ref()
+                    .map(|(_, upper)| upper.to_bytes())
+                    .unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    /// Number of entries written so far with status `ADDED`.
+    pub fn added_files_count(&self) -> i32 {
+        self.added_files_count
+    }
+    /// Number of entries written so far with status `EXISTING`.
+    pub fn existing_files_count(&self) -> i32 {
+        self.existing_files_count
+    }
+    /// Number of entries written so far with status `DELETED`.
+    pub fn deleted_files_count(&self) -> i32 {
+        self.deleted_files_count
+    }
+    /// Total row count of entries written so far with status `ADDED`.
+    pub fn added_rows_count(&self) -> i64 {
+        self.added_rows_count
+    }
+    /// Total row count of entries written so far with status `EXISTING`.
+    pub fn existing_rows_count(&self) -> i64 {
+        self.existing_rows_count
+    }
+    /// Total row count of entries written so far with status `DELETED`.
+    pub fn deleted_rows_count(&self) -> i64 {
+        self.deleted_rows_count
+    }
+    /// Whether this manifest references data files or delete files, or `None` if nothing has
+    /// been written to it yet.
+    pub fn content(&self) -> Option<ManifestContent> {
+        self.content
+    }
+
+    /// Flushes the Avro file and returns its encoded bytes.
+    pub fn into_inner(self) -> Result<Vec<u8>> {
+        self.inner
+            .into_inner()
+            .map_err(|e| anyhow!("failed to finalize manifest file: {e:?}"))
+    }
+}
+
+fn bail_unknown_status(status: i32) -> Result<()> {
+    anyhow::bail!("unknown manifest entry status: {status}")
+}
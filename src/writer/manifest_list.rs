@@ -0,0 +1,67 @@
+//! Writes [`ManifestFile`] records to a manifest list Avro file.
+
+use anyhow::{anyhow, Result};
+use apache_avro::Writer as AvroWriter;
+
+use crate::model::manifest::FormatVersion;
+use crate::model::manifest::ManifestFile;
+
+use super::schema::manifest_file_to_avro_value;
+
+/// Writes manifest list entries to an Avro file. Each [`ManifestFile`] is typically built from
+/// a finished [`ManifestWriter`](super::manifest::ManifestWriter)'s counts and
+/// [`field_summaries`](super::manifest::ManifestWriter::field_summaries), plus the manifest's
+/// location and length once it has been written out.
+pub struct ManifestListWriter<'a> {
+    inner: AvroWriter<'a, Vec<u8>>,
+    format_version: FormatVersion,
+}
+
+impl<'a> ManifestListWriter<'a> {
+    /// Creates a writer for a new manifest list written with the given `format_version`,
+    /// against `schema` (built with
+    /// [`parse_manifest_list_schema`](super::schema::parse_manifest_list_schema) for the same
+    /// `format_version`), for the snapshot it belongs to.
+    pub fn new(
+        schema: &'a apache_avro::Schema,
+        format_version: FormatVersion,
+        snapshot_id: i64,
+        sequence_number: i64,
+    ) -> Result<Self> {
+        let mut inner = AvroWriter::new(schema, Vec::new());
+        inner
+            .add_user_metadata(
+                "format-version".to_string(),
+                (format_version as u8).to_string(),
+            )
+            .map_err(|e| anyhow!("failed to set manifest list format-version: {e:?}"))?;
+        inner
+            .add_user_metadata("snapshot-id".to_string(), snapshot_id.to_string())
+            .map_err(|e| anyhow!("failed to set manifest list snapshot-id: {e:?}"))?;
+        inner
+            .add_user_metadata("sequence-number".to_string(), sequence_number.to_string())
+            .map_err(|e| anyhow!("failed to set manifest list sequence-number: {e:?}"))?;
+        Ok(Self {
+            inner,
+            format_version,
+        })
+    }
+
+    /// Writes one manifest list entry, using the on-disk field names `format_version` requires.
+    pub fn write(&mut self, manifest_file: &ManifestFile) -> Result<()> {
+        self.inner
+            .append(manifest_file_to_avro_value(
+                manifest_file,
+                self.format_version,
+            ))
+            .map_err(|e| anyhow!("failed to write manifest list entry: {e:?}"))?;
+        Ok(())
+    }
+
+    /// Flushes the Avro file and returns its encoded bytes.
+    pub fn into_inner(self) -> Result<Vec<u8>> {
+        self.inner
+            .into_inner()
+            .map_err(|e| anyhow!("failed to finalize manifest list file: {e:?}"))
+    }
+}
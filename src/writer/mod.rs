@@ -0,0 +1,168 @@
+//! Avro writers for manifests and manifest lists — the counterpart to the read path in
+//! [`crate::model::manifest`], needed to produce the files any append/commit transaction flow
+//! writes back to the table.
+
+pub mod manifest;
+pub mod manifest_list;
+pub mod schema;
+
+pub use manifest::ManifestWriter;
+pub use manifest_list::ManifestListWriter;
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+    use serde_json::json;
+
+    use crate::model::manifest::{DataFile, FormatVersion, ManifestEntry};
+    use crate::model::schema::Schema;
+    use crate::model::types::{PrimitiveType, StructField, Type};
+
+    use super::schema::{parse_manifest_entry_schema, parse_manifest_list_schema};
+    use super::{ManifestListWriter, ManifestWriter};
+
+    #[test]
+    fn test_manifest_and_manifest_list_round_trip() -> Result<()> {
+        let partition_fields = vec![("category".to_string(), 1000, Type::Primitive(PrimitiveType::String))];
+        let entry_schema = parse_manifest_entry_schema(&partition_fields)?;
+        let table_schema = Schema {
+            type_tag: "struct".to_string(),
+            schema_id: 0,
+            identifier_field_ids: vec![],
+            fields: vec![StructField {
+                id: 1,
+                name: "category".to_string(),
+                required: false,
+                field_type: Type::Primitive(PrimitiveType::String),
+                doc: None,
+            }],
+        };
+        let partition_spec = json!([
+            {"source-id": 1, "field-id": 1000, "name": "category", "transform": "identity"},
+        ]);
+        let mut manifest_writer = ManifestWriter::new(
+            &entry_schema,
+            FormatVersion::V2,
+            vec![("category".to_string(), Type::Primitive(PrimitiveType::String))],
+            &table_schema,
+            partition_spec,
+            0,
+        )?;
+
+        let data_file: DataFile = serde_json::from_value(json!({
+            "file_path": "s3://bucket/data/a.parquet",
+            "file_format": "PARQUET",
+            "partition": {"category": "fruit"},
+            "record_count": 10,
+            "file_size_in_bytes": 1234,
+            "content": 0,
+            "sort_order_id": 0,
+        }))?;
+        manifest_writer.write(&ManifestEntry {
+            status: 1,
+            snapshot_id: Some(1),
+            data_file,
+            sequence_number: Some(1),
+            file_sequence_number: Some(1),
+        })?;
+
+        assert_eq!(manifest_writer.added_files_count(), 1);
+        assert_eq!(manifest_writer.added_rows_count(), 10);
+        assert_eq!(manifest_writer.content(), Some(crate::model::manifest::ManifestContent::Data));
+        let field_summaries = manifest_writer.field_summaries();
+        assert_eq!(field_summaries.len(), 1);
+        assert!(!field_summaries[0].contains_null);
+        assert_eq!(field_summaries[0].lower_bound, b"fruit".to_vec());
+
+        let manifest_bytes = manifest_writer.into_inner()?;
+        let reader = apache_avro::Reader::new(manifest_bytes.as_slice())?;
+        assert_eq!(reader.count(), 1);
+
+        let list_schema = parse_manifest_list_schema(FormatVersion::V2)?;
+        let mut list_writer = ManifestListWriter::new(&list_schema, FormatVersion::V2, 1, 1)?;
+        list_writer.write(&crate::model::manifest::ManifestFile {
+            manifest_path: "s3://bucket/metadata/m1.avro".to_string(),
+            manifest_length: manifest_bytes.len() as i64,
+            added_snapshot_id: 1,
+            sequence_number: Some(1),
+            min_sequence_number: Some(1),
+            added_files_count: Some(1),
+            existing_files_count: Some(0),
+            deleted_fields_count: Some(0),
+            partitions: field_summaries,
+            added_rows_count: Some(10),
+            existing_rows_count: Some(0),
+            deleted_rows_count: Some(0),
+            partition_spec_id: 0,
+            content: manifest_writer.content(),
+        })?;
+        let list_bytes = list_writer.into_inner()?;
+        let list_reader = apache_avro::Reader::new(list_bytes.as_slice())?;
+        assert_eq!(list_reader.count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_manifest_list_schema_omits_v2_only_fields_for_v1() -> Result<()> {
+        let schema = super::schema::manifest_list_schema(FormatVersion::V1);
+        let field_names: Vec<&str> = schema["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|field| field["name"].as_str().unwrap())
+            .collect();
+        assert!(field_names.contains(&"deleted_files_count"));
+        assert!(!field_names.contains(&"deleted_data_files_count"));
+        assert!(!field_names.contains(&"sequence_number"));
+        assert!(!field_names.contains(&"content"));
+
+        let schema = super::schema::manifest_list_schema(FormatVersion::V2);
+        let field_names: Vec<&str> = schema["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|field| field["name"].as_str().unwrap())
+            .collect();
+        assert!(field_names.contains(&"deleted_data_files_count"));
+        assert!(field_names.contains(&"sequence_number"));
+        assert!(field_names.contains(&"content"));
+        Ok(())
+    }
+
+    /// Reads the same manifest fixture `crate::model::manifest`'s read-side tests exercise, and
+    /// checks that every entry it contains can be written back out through [`ManifestWriter`]
+    /// and read back. No partition fields are declared here since the fixture's partition spec
+    /// isn't modeled by this test; that only means partition bounds aren't folded, not that
+    /// entries are skipped.
+    #[test]
+    fn test_manifest_writer_round_trips_existing_fixture() -> Result<()> {
+        let manifest_path = "test-data/metadata/9624c71f-198f-47fe-824b-0291f8998018-m1.avro";
+        let bytes = std::fs::read(manifest_path)?;
+        let entries =
+            crate::model::manifest::parse_with_version(&bytes, FormatVersion::V2, 1, 6560075252320843098)?;
+
+        let entry_schema = parse_manifest_entry_schema(&[])?;
+        let table_schema = Schema {
+            type_tag: "struct".to_string(),
+            schema_id: 0,
+            identifier_field_ids: vec![],
+            fields: vec![],
+        };
+        let mut manifest_writer = ManifestWriter::new(
+            &entry_schema,
+            FormatVersion::V2,
+            vec![],
+            &table_schema,
+            json!([]),
+            0,
+        )?;
+        for entry in &entries {
+            manifest_writer.write(entry)?;
+        }
+
+        let manifest_bytes = manifest_writer.into_inner()?;
+        let reader = apache_avro::Reader::new(manifest_bytes.as_slice())?;
+        assert_eq!(reader.count(), entries.len());
+        Ok(())
+    }
+}
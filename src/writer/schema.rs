@@ -0,0 +1,383 @@
+//! Builds the [Avro](https://avro.apache.org/) schemas used to write manifests and manifest
+//! lists, tagging each field with the `field-id` property the spec assigns to it so that a
+//! written file round-trips through Iceberg's id-based schema evolution rules.
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use crate::model::manifest::FormatVersion;
+use crate::model::types::{PrimitiveType, Type};
+
+/// Maps an Iceberg [`Type`] to the Avro type used to encode it, without the enclosing
+/// `name`/`field-id`/`default` wrapper a record field needs.
+fn avro_base_type(r#type: &Type, name_hint: &str) -> Value {
+    match r#type {
+        Type::Primitive(PrimitiveType::Boolean) => json!("boolean"),
+        Type::Primitive(PrimitiveType::Int) => json!("int"),
+        Type::Primitive(PrimitiveType::Long) => json!("long"),
+        Type::Primitive(PrimitiveType::Float) => json!("float"),
+        Type::Primitive(PrimitiveType::Double) => json!("double"),
+        Type::Primitive(PrimitiveType::String) => json!("string"),
+        Type::Primitive(PrimitiveType::Binary) => json!("bytes"),
+        Type::Primitive(PrimitiveType::Uuid) => json!({
+            "type": "fixed",
+            "name": format!("{name_hint}_uuid"),
+            "size": 16,
+            "logicalType": "uuid",
+        }),
+        Type::Primitive(PrimitiveType::Fixed(len)) => json!({
+            "type": "fixed",
+            "name": format!("{name_hint}_fixed"),
+            "size": len,
+        }),
+        Type::Primitive(PrimitiveType::Date) => json!({"type": "int", "logicalType": "date"}),
+        Type::Primitive(PrimitiveType::Time) => {
+            json!({"type": "long", "logicalType": "time-micros"})
+        }
+        Type::Primitive(PrimitiveType::Timestamp) => {
+            json!({"type": "long", "logicalType": "timestamp-micros"})
+        }
+        Type::Primitive(PrimitiveType::Timestamptz) => {
+            json!({"type": "long", "logicalType": "timestamp-micros", "adjust-to-utc": true})
+        }
+        Type::Primitive(PrimitiveType::Decimal { precision, scale }) => json!({
+            "type": "bytes",
+            "logicalType": "decimal",
+            "precision": precision,
+            "scale": scale,
+        }),
+        // Nested partition values are not supported by the writer yet.
+        Type::Struct(_) | Type::List(_) | Type::Map(_) => json!("bytes"),
+    }
+}
+
+/// Builds a record field with the given `name` and `field_id`, required or nullable.
+fn field(name: &str, field_id: i32, r#type: &Type, required: bool) -> Value {
+    let base = avro_base_type(r#type, name);
+    if required {
+        json!({"name": name, "type": base, "field-id": field_id})
+    } else {
+        json!({"name": name, "type": ["null", base], "default": Value::Null, "field-id": field_id})
+    }
+}
+
+/// Builds a record field for one of `DataFile`'s column-id-keyed maps, encoded the way the
+/// rest of the crate reads them: as an array of `{key, value}` records.
+fn map_field(name: &str, field_id: i32, key_id: i32, value_id: i32, value_type: &str) -> Value {
+    json!({
+        "name": name,
+        "type": ["null", {
+            "type": "array",
+            "items": {
+                "type": "record",
+                "name": format!("k{key_id}_v{value_id}"),
+                "fields": [
+                    {"name": "key", "type": "int", "field-id": key_id},
+                    {"name": "value", "type": value_type, "field-id": value_id},
+                ],
+            },
+            "element-id": field_id,
+        }],
+        "default": Value::Null,
+        "field-id": field_id,
+    })
+}
+
+/// Builds the Avro schema for a `manifest_entry` record, embedding a `partition` record whose
+/// fields are `partition_fields` (name, field id and type, in partition spec order).
+pub fn manifest_entry_schema(partition_fields: &[(String, i32, Type)]) -> Value {
+    let partition_record = json!({
+        "type": "record",
+        "name": "r102",
+        "fields": partition_fields
+            .iter()
+            .map(|(name, id, ty)| field(name, *id, ty, true))
+            .collect::<Vec<_>>(),
+    });
+
+    let data_file_fields = vec![
+        field("content", 134, &Type::Primitive(PrimitiveType::Int), true),
+        field(
+            "file_path",
+            100,
+            &Type::Primitive(PrimitiveType::String),
+            true,
+        ),
+        field(
+            "file_format",
+            101,
+            &Type::Primitive(PrimitiveType::String),
+            true,
+        ),
+        json!({"name": "partition", "type": partition_record, "field-id": 102}),
+        field(
+            "record_count",
+            103,
+            &Type::Primitive(PrimitiveType::Long),
+            true,
+        ),
+        field(
+            "file_size_in_bytes",
+            104,
+            &Type::Primitive(PrimitiveType::Long),
+            true,
+        ),
+        map_field("column_sizes", 108, 117, 118, "long"),
+        map_field("value_counts", 109, 119, 120, "long"),
+        map_field("null_value_counts", 110, 121, 122, "long"),
+        map_field("nan_value_counts", 137, 138, 139, "long"),
+        map_field("distinct_counts", 111, 123, 124, "long"),
+        map_field("lower_bounds", 125, 126, 127, "bytes"),
+        map_field("upper_bounds", 128, 129, 130, "bytes"),
+        field(
+            "key_metadata",
+            131,
+            &Type::Primitive(PrimitiveType::Binary),
+            false,
+        ),
+        json!({
+            "name": "split_offsets", "field-id": 132, "default": Value::Null,
+            "type": ["null", {"type": "array", "items": "long", "element-id": 133}],
+        }),
+        json!({
+            "name": "equality_ids", "field-id": 135, "default": Value::Null,
+            "type": ["null", {"type": "array", "items": "int", "element-id": 136}],
+        }),
+        field(
+            "sort_order_id",
+            140,
+            &Type::Primitive(PrimitiveType::Int),
+            false,
+        ),
+    ];
+
+    json!({
+        "type": "record",
+        "name": "manifest_entry",
+        "fields": [
+            {"name": "status", "type": "int", "field-id": 0},
+            {"name": "snapshot_id", "type": ["null", "long"], "default": Value::Null, "field-id": 1},
+            {"name": "sequence_number", "type": ["null", "long"], "default": Value::Null, "field-id": 3},
+            {"name": "file_sequence_number", "type": ["null", "long"], "default": Value::Null, "field-id": 4},
+            {
+                "name": "data_file", "field-id": 2,
+                "type": {"type": "record", "name": "r2", "fields": data_file_fields},
+            },
+        ],
+    })
+}
+
+/// Parses the Avro schema for a `manifest_entry` record, ready to hand to an
+/// [`apache_avro::Writer`].
+pub fn parse_manifest_entry_schema(
+    partition_fields: &[(String, i32, Type)],
+) -> Result<apache_avro::Schema> {
+    apache_avro::Schema::parse(&manifest_entry_schema(partition_fields))
+        .map_err(|e| anyhow!("failed to build manifest entry schema: {e:?}"))
+}
+
+/// Parses the Avro schema for a `manifest_file` record, ready to hand to an
+/// [`apache_avro::Writer`].
+pub fn parse_manifest_list_schema(format_version: FormatVersion) -> Result<apache_avro::Schema> {
+    apache_avro::Schema::parse(&manifest_list_schema(format_version))
+        .map_err(|e| anyhow!("failed to build manifest list schema: {e:?}"))
+}
+
+/// Builds the Avro schema for a `manifest_file` record (a manifest list entry), using the
+/// on-disk field names and field presence the spec assigns to `format_version`: v1 has no
+/// sequence numbers or `content`, and names its count fields `{added,existing,deleted}_files_count`;
+/// v2 adds sequence numbers and `content`, and names its count fields
+/// `{added,existing,deleted}_data_files_count`.
+pub fn manifest_list_schema(format_version: FormatVersion) -> Value {
+    let (added_files_count, existing_files_count, deleted_files_count) = match format_version {
+        FormatVersion::V1 => (
+            "added_files_count",
+            "existing_files_count",
+            "deleted_files_count",
+        ),
+        FormatVersion::V2 => (
+            "added_data_files_count",
+            "existing_data_files_count",
+            "deleted_data_files_count",
+        ),
+    };
+
+    let mut fields = vec![
+        json!({"name": "manifest_path", "type": "string", "field-id": 500}),
+        json!({"name": "manifest_length", "type": "long", "field-id": 501}),
+        json!({"name": "partition_spec_id", "type": "int", "field-id": 502}),
+        json!({"name": "added_snapshot_id", "type": "long", "field-id": 503}),
+    ];
+    if format_version == FormatVersion::V2 {
+        fields.push(json!({"name": "sequence_number", "type": ["null", "long"], "default": Value::Null, "field-id": 515}));
+        fields.push(json!({"name": "min_sequence_number", "type": ["null", "long"], "default": Value::Null, "field-id": 516}));
+    }
+    fields.push(json!({"name": added_files_count, "type": ["null", "int"], "default": Value::Null, "field-id": 504}));
+    fields.push(json!({"name": existing_files_count, "type": ["null", "int"], "default": Value::Null, "field-id": 505}));
+    fields.push(json!({"name": deleted_files_count, "type": ["null", "int"], "default": Value::Null, "field-id": 506}));
+    if format_version == FormatVersion::V2 {
+        fields.push(json!({"name": "content", "type": ["null", "int"], "default": Value::Null, "field-id": 517}));
+    }
+    fields.push(json!({"name": "added_rows_count", "type": ["null", "long"], "default": Value::Null, "field-id": 512}));
+    fields.push(json!({"name": "existing_rows_count", "type": ["null", "long"], "default": Value::Null, "field-id": 513}));
+    fields.push(json!({"name": "deleted_rows_count", "type": ["null", "long"], "default": Value::Null, "field-id": 514}));
+    fields.push(json!({
+        "name": "partitions", "field-id": 507, "default": Value::Null,
+        "type": ["null", {
+            "type": "array",
+            "element-id": 508,
+            "items": {
+                "type": "record",
+                "name": "r508",
+                "fields": [
+                    {"name": "contains_null", "type": "boolean", "field-id": 509},
+                    {"name": "contains_nan", "type": ["null", "boolean"], "default": Value::Null, "field-id": 518},
+                    {"name": "lower_bound", "type": ["null", "bytes"], "default": Value::Null, "field-id": 510},
+                    {"name": "upper_bound", "type": ["null", "bytes"], "default": Value::Null, "field-id": 511},
+                ],
+            },
+        }],
+    }));
+
+    json!({
+        "type": "record",
+        "name": "manifest_file",
+        "fields": fields,
+    })
+}
+
+/// Builds the Avro [`Value::Record`](apache_avro::types::Value) for one `manifest_file` entry,
+/// using the field names [`manifest_list_schema`] declared for `format_version` — written
+/// directly rather than through `manifest_file`'s serde impl, since the Rust field names (and
+/// therefore its serialized field names) don't vary with `format_version` the way the on-disk
+/// schema must.
+pub fn manifest_file_to_avro_value(
+    manifest_file: &crate::model::manifest::ManifestFile,
+    format_version: FormatVersion,
+) -> apache_avro::types::Value {
+    use apache_avro::types::Value as Avro;
+
+    let (added_files_count, existing_files_count, deleted_files_count) = match format_version {
+        FormatVersion::V1 => (
+            "added_files_count",
+            "existing_files_count",
+            "deleted_files_count",
+        ),
+        FormatVersion::V2 => (
+            "added_data_files_count",
+            "existing_data_files_count",
+            "deleted_data_files_count",
+        ),
+    };
+
+    let mut fields = vec![
+        (
+            "manifest_path".to_string(),
+            Avro::String(manifest_file.manifest_path.clone()),
+        ),
+        (
+            "manifest_length".to_string(),
+            Avro::Long(manifest_file.manifest_length),
+        ),
+        (
+            "partition_spec_id".to_string(),
+            Avro::Int(manifest_file.partition_spec_id),
+        ),
+        (
+            "added_snapshot_id".to_string(),
+            Avro::Long(manifest_file.added_snapshot_id),
+        ),
+    ];
+    if format_version == FormatVersion::V2 {
+        fields.push((
+            "sequence_number".to_string(),
+            opt_long(manifest_file.sequence_number),
+        ));
+        fields.push((
+            "min_sequence_number".to_string(),
+            opt_long(manifest_file.min_sequence_number),
+        ));
+    }
+    fields.push((
+        added_files_count.to_string(),
+        opt_int(manifest_file.added_files_count),
+    ));
+    fields.push((
+        existing_files_count.to_string(),
+        opt_int(manifest_file.existing_files_count),
+    ));
+    fields.push((
+        deleted_files_count.to_string(),
+        opt_int(manifest_file.deleted_fields_count),
+    ));
+    if format_version == FormatVersion::V2 {
+        fields.push((
+            "content".to_string(),
+            opt_int(manifest_file.content.map(i32::from)),
+        ));
+    }
+    fields.push((
+        "added_rows_count".to_string(),
+        opt_long(manifest_file.added_rows_count),
+    ));
+    fields.push((
+        "existing_rows_count".to_string(),
+        opt_long(manifest_file.existing_rows_count),
+    ));
+    fields.push((
+        "deleted_rows_count".to_string(),
+        opt_long(manifest_file.deleted_rows_count),
+    ));
+    fields.push((
+        "partitions".to_string(),
+        some(Avro::Array(
+            manifest_file
+                .partitions
+                .iter()
+                .map(field_summary_to_avro)
+                .collect(),
+        )),
+    ));
+
+    Avro::Record(fields)
+}
+
+fn field_summary_to_avro(summary: &crate::model::manifest::FieldSummary) -> apache_avro::types::Value {
+    use apache_avro::types::Value as Avro;
+    Avro::Record(vec![
+        ("contains_null".to_string(), Avro::Boolean(summary.contains_null)),
+        ("contains_nan".to_string(), some(Avro::Boolean(summary.contains_nan))),
+        ("lower_bound".to_string(), opt_bytes(&summary.lower_bound)),
+        ("upper_bound".to_string(), opt_bytes(&summary.upper_bound)),
+    ])
+}
+
+/// Wraps a present value of a nullable (`["null", T]`) Avro union field.
+fn some(value: apache_avro::types::Value) -> apache_avro::types::Value {
+    apache_avro::types::Value::Union(1, Box::new(value))
+}
+
+fn opt_int(value: Option<i32>) -> apache_avro::types::Value {
+    use apache_avro::types::Value as Avro;
+    match value {
+        Some(v) => some(Avro::Int(v)),
+        None => Avro::Union(0, Box::new(Avro::Null)),
+    }
+}
+
+fn opt_long(value: Option<i64>) -> apache_avro::types::Value {
+    use apache_avro::types::Value as Avro;
+    match value {
+        Some(v) => some(Avro::Long(v)),
+        None => Avro::Union(0, Box::new(Avro::Null)),
+    }
+}
+
+fn opt_bytes(bytes: &[u8]) -> apache_avro::types::Value {
+    use apache_avro::types::Value as Avro;
+    if bytes.is_empty() {
+        Avro::Union(0, Box::new(Avro::Null))
+    } else {
+        some(Avro::Bytes(bytes.to_vec()))
+    }
+}